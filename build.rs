@@ -0,0 +1,20 @@
+use std::env;
+use std::process::Command;
+
+fn main() {
+	println!("cargo:rerun-if-changed=build.rs");
+	println!("cargo:rustc-check-cfg=cfg(postfix_macros_once_lock)");
+	// std::sync::OnceLock and const Mutex::new are needed for the
+	// global state of report_err! and count_calls!
+	if rustc_minor_version().unwrap_or(0) >= 70 {
+		println!("cargo:rustc-cfg=postfix_macros_once_lock");
+	}
+}
+
+fn rustc_minor_version() -> Option<u32> {
+	let rustc = env::var_os("RUSTC")?;
+	let output = Command::new(rustc).arg("--version").output().ok()?;
+	let version = String::from_utf8(output.stdout).ok()?;
+	// Format: "rustc 1.XX.Y (hash date)"
+	version.split_whitespace().nth(1)?.split('.').nth(1)?.parse().ok()
+}
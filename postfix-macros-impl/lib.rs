@@ -30,7 +30,7 @@ postfix_macros! {
 
 extern crate proc_macro;
 use proc_macro::{TokenStream, TokenTree as Tt, Punct, Group, Spacing,
-	Delimiter};
+	Delimiter, Ident, Span};
 
 #[proc_macro]
 pub fn postfix_macros(stream :TokenStream) -> TokenStream {
@@ -40,6 +40,40 @@ pub fn postfix_macros(stream :TokenStream) -> TokenStream {
 	res
 }
 
+/// Implementation detail of the helper macros of the
+/// `postfix-macros` crate, not meant for direct usage.
+///
+/// Takes `name (value) { body }` and turns it into
+/// `{ let name = value; body }`, where `name` gets the
+/// hygiene of the body, so that the body can refer to it.
+/// This way `macro_rules` macros can provide bindings
+/// with conventional names like `it` to their users.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __implicit_binding(stream :TokenStream) -> TokenStream {
+	let mut stream_iter = stream.into_iter();
+	let (name, value, body) = match (stream_iter.next(), stream_iter.next(), stream_iter.next()) {
+		(Some(Tt::Ident(name)), Some(Tt::Group(value)), Some(Tt::Group(body))) => (name, value, body),
+		_ => panic!("expected name, value and body"),
+	};
+	let body = body.stream();
+	let span = body.clone().into_iter().next()
+		.map(|tt| tt.span())
+		.unwrap_or_else(Span::call_site);
+	let name = Ident::new(&name.to_string(), span);
+
+	let mut res = TokenStream::new();
+	res.extend(vec![
+		Tt::Ident(Ident::new("let", Span::call_site())),
+		Tt::Ident(name),
+		Tt::Punct(Punct::new('=', Spacing::Alone)),
+	]);
+	res.extend(value.stream());
+	res.extend(std::iter::once(Tt::Punct(Punct::new(';', Spacing::Alone))));
+	res.extend(body);
+	TokenStream::from(Tt::Group(Group::new(Delimiter::Brace, res)))
+}
+
 struct Visitor;
 
 impl Visitor {
@@ -49,19 +83,12 @@ impl Visitor {
 		while let Some(tt) = stream_iter.next() {
 			match tt {
 				Tt::Group(group) => {
-					let mut postfix_macro = false;
-					{
-						let last_three = res.rchunks(3).next();
-						if let Some(&[Tt::Punct(ref p1), Tt::Ident(_), Tt::Punct(ref p2)]) = last_three {
-							if (p1.as_char(), p1.spacing(), p2.as_char(), p2.spacing()) == ('.', Spacing::Alone, '!', Spacing::Alone) {
-								postfix_macro = true;
-							}
-						}
-					}
-					let group = if postfix_macro {
-						// Remove the ! and macro ident
+					let path_len = postfix_macro_path_len(&res);
+					let group = if let Some(path_len) = path_len {
+						// Remove the ! and macro path
 						let mac_bang = res.pop().unwrap();
-						let mac = res.pop().unwrap();
+						let mut path = res.split_off(res.len() - path_len);
+						let mac = path.pop().unwrap();
 						// Remove the . before the macro
 						res.pop().unwrap();
 
@@ -80,8 +107,9 @@ impl Visitor {
 						let gr = prepend_macro_arg_to_group(arg_tokens, gr);
 						res.truncate(res.len() - expr_len);
 
-						// Add back the macro ident and bang
-						res.push(mac);
+						// Add back the macro path and bang
+						res.extend(path);
+						res.push(macro_name(mac));
 						res.push(mac_bang);
 
 						/*println!("res so far: {}",
@@ -117,6 +145,35 @@ impl Visitor {
 	}
 }
 
+/// Checks whether the tt's end in a postfix macro
+/// invocation like `.foo!` or `.path::to::foo!`,
+/// and if so, returns the number of token tree items
+/// that make up the path of the macro.
+fn postfix_macro_path_len(tts :&[Tt]) -> Option<usize> {
+	let is_punct = |tt :&Tt, ch :char, spacing :Spacing| match tt {
+		Tt::Punct(p) => (p.as_char(), p.spacing()) == (ch, spacing),
+		_ => false,
+	};
+	let (bang, path_and_dot) = tts.split_last()?;
+	if !is_punct(bang, '!', Spacing::Alone) {
+		return None;
+	}
+	let mut path_len = 0;
+	loop {
+		let before = &path_and_dot[..path_and_dot.len() - path_len];
+		match before {
+			[.., colon_1, colon_2, Tt::Ident(_)]
+					if is_punct(colon_1, ':', Spacing::Joint) && is_punct(colon_2, ':', Spacing::Alone) => {
+				// Part of a path like `.path::to::foo!`
+				path_len += 3;
+			},
+			[.., dot, Tt::Ident(_)] if is_punct(dot, '.', Spacing::Alone) => {
+				return Some(path_len + 1);
+			},
+			_ => return None,
+		}
+	}
+}
 
 /// Walk the entire chain of tt's that
 /// form an expression that a postfix macro call
@@ -340,6 +397,40 @@ fn expression_length(tts :&[Tt]) -> usize {
 	expr_len
 }
 
+/// Keywords that can't be used as macro names directly,
+/// but can be used as raw identifiers.
+const RAW_KEYWORDS :&[&str] = &["as", "async", "await", "break", "const",
+	"continue", "dyn", "else", "enum", "extern", "false", "fn", "for", "if",
+	"impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+	"return", "static", "struct", "trait", "true", "type", "unsafe", "use",
+	"where", "while", "abstract", "become", "box", "do", "final", "macro",
+	"override", "priv", "try", "typeof", "unsized", "virtual", "yield"];
+
+/// Turns the name of a postfix macro into the name of
+/// the traditional macro invocation.
+///
+/// Keywords like in `.match!{}` are turned into raw
+/// identifiers, so that they can be used as macro names.
+fn macro_name(mac :Tt) -> Tt {
+	let id = match &mac {
+		Tt::Ident(id) => id,
+		_ => return mac,
+	};
+	let id_str = id.to_string();
+	if !RAW_KEYWORDS.contains(&id_str.as_str()) {
+		return mac;
+	}
+	// Ident::new_raw is not available on our MSRV,
+	// so go through the parser instead.
+	let raw = format!("r#{}", id_str).parse::<TokenStream>().unwrap();
+	let mut raw_id :Ident = match raw.into_iter().next() {
+		Some(Tt::Ident(raw_id)) => raw_id,
+		_ => unreachable!(),
+	};
+	raw_id.set_span(id.span());
+	Tt::Ident(raw_id)
+}
+
 fn prepend_macro_arg_to_group(tokens :&[Tt], gr :Group) -> Group {
 	// Build the expr's tt.
 	// If there is only one token and it's
@@ -25,9 +25,17 @@ for your greatest convenience.
 | - | - |
 | `unwrap_or`, `unwrap_or_else` | [`unwrap_or!`] |
 | **`if let`** with else clause | [`match_or!`] |
+| **`if let`** as an expression | [`if_let!`] |
+| **`match`** | [`match!`](macro.match.html) |
 | **`match`** with default case | [`match_or!`] |
-| **`if`** `<bool>`, `bool::then` | [`then!`] |
+| **`if`** `<bool>` | [`then!`] |
+| `bool::then` | [`then_some!`] |
 | **`else`** | [`then_else!`] |
+| `filter(\|x\| matches!(x, ..))` | [`filter_matches!`] |
+| `find(\|x\| matches!(x, ..))` | [`find_matches!`] |
+| `filter(\|x\| matches!(x, ..)).count()` | [`count_matches!`] |
+| `position(\|x\| matches!(x, ..))` | [`position_matches!`] |
+| `any(\|x\| matches!(x, ..))`, `all(..)` | [`any_matches!`], [`all_matches!`] |
 */
 #![forbid(unsafe_code)]
 
@@ -40,8 +48,38 @@ The main macro of this crate.
 The macro scans for `expr.macro_invoc!(params)` patterns
 and changes them to `macro_invoc!(expr, params)` patterns.
 
+The macro can also be referred to by its path, as in
+`expr.path::to::macro_invoc!(params)`, which is kept
+in the rewritten `path::to::macro_invoc!(expr, params)`,
+so the macro doesn't need to be imported.
+
 If no parameters are passed to the postfix macro,
 then no trailing `,` is emitted.
+
+Unless `expr` is a single identifier or literal,
+it is wrapped in braces, as in `macro_invoc!({expr}, params)`.
+Macros that need `expr` as a place, like [`replace_with!`],
+can match `{ $($place:tt)* }` to obtain its tokens unwrapped.
+
+If the name of the postfix macro is a keyword,
+like in `.match!{}`, it is turned into a raw
+identifier, in this case `r#match!{}`.
+
+There is no attribute macro counterpart, like a `#[postfix_macros]`
+to put on functions or modules. The input of attribute macros
+has to be valid Rust syntax, and the parser rejects
+`expr.macro_invoc!(params)` before any attribute macro can run.
+Instead, the body of the item can be wrapped in the macro:
+
+```
+# use postfix_macros::postfix_macros;
+postfix_macros! {
+	fn double(v :u32) -> u32 {
+		(v * 2).dbg!()
+	}
+}
+assert_eq!(double(21), 42);
+```
 */
 pub use postfix_macros_impl::postfix_macros;
 
@@ -145,14 +183,15 @@ Executes the body if the argument is `true`
 Meant to be used in a postfix context, as
 the postfix analog of **`if`**.
 
-With the `bool::then` function, there is a
-[currently unstable](https://github.com/rust-lang/rust/issues/64260)
+With the `bool::then` function, there is an
 equivalent in the standard library, but
 the macro doesn't put the body into a
 closure, and is thus more powerful.
+The body can consist of multiple statements.
 
 Evaluates the first argument as a boolean,
 and if it's `true`, executes the body.
+To obtain the body's value, see [`then_some!`].
 
 ```
 # use postfix_macros::{postfix_macros, then};
@@ -175,6 +214,39 @@ macro_rules! then {
 	};
 }
 
+/**
+Evaluates the body if the argument is `true`, to an `Option`
+
+Meant to be used in a postfix context.
+Like [`then!`], but like `bool::then`, the macro
+evaluates to `Some` of the body's value if
+the argument is `true`, and to `None` otherwise.
+Unlike `bool::then`, the body isn't put into a closure,
+so it can use control flow like `return` or `?`.
+
+```
+# use postfix_macros::{postfix_macros, then_some};
+# postfix_macros! {
+let mut w = 75;
+let limit = 10;
+let v = (w > limit).then_some!{ w -= limit; w };
+assert_eq!(v, Some(65));
+let v = (w < limit).then_some!{ w };
+assert_eq!(v, None);
+# }
+```
+*/
+#[macro_export]
+macro_rules! then_some {
+	($v:expr, $($body:tt)*) => {
+		if $v {
+			Some({ $($body)* })
+		} else {
+			None
+		}
+	};
+}
+
 /**
 **`else`** clauses for the [`then!`] macro
 
@@ -215,3 +287,6712 @@ macro_rules! then_else {
 		}
 	};
 }
+
+/**
+Unwraps a `Result`, or reports the error and exits the process
+
+Meant to be used in a postfix context, to replace
+the usual boilerplate at the top of `main` functions
+of command line tools.
+
+If the argument is `Ok`, the macro evaluates to the
+contained value. Otherwise, the error is printed to
+stderr, and the process is terminated via
+[`std::process::exit`] with the given exit code,
+or `1` if none is given.
+The error is printed via its `Display` impl.
+If it implements [`std::error::Error`],
+its chain of sources gets printed as well.
+
+```no_run
+# use postfix_macros::{postfix_macros, exit_if_err};
+fn run(args :Vec<String>) -> Result<u32, std::num::ParseIntError> {
+	args[1].parse()
+}
+# postfix_macros! {
+let v = run(std::env::args().collect()).exit_if_err!(2);
+println!("{}", v);
+# }
+```
+*/
+#[macro_export]
+macro_rules! exit_if_err {
+	($v:expr) => {
+		$crate::exit_if_err!($v, 1)
+	};
+	($v:expr, $code:expr) => {
+		match $v {
+			Ok(v) => v,
+			Err(e) => {
+				#[allow(unused_imports)]
+				use $crate::__private::{ReportViaError, ReportViaDisplay};
+				eprintln!("Error: {}", (&&$crate::__private::Report(&e)).report());
+				std::process::exit($code)
+			},
+		}
+	};
+}
+
+/**
+Prints the `Err` variant of a `Result` like [`dbg!`] would
+
+Meant to be used in a postfix context, to temporarily
+instrument the error path in the middle of a long
+chain of combinators.
+
+If the argument is an `Err`, the error is printed to stderr,
+together with the file, line and column of the invocation.
+The argument is passed through unchanged, so unlike
+[`dbg!`], only the error is required to implement `Debug`.
+
+See [`inspect_ok_dbg!`] for the `Ok` counterpart.
+
+```
+# use postfix_macros::{postfix_macros, inspect_err_dbg};
+# postfix_macros! {
+let v = u32::from_str_radix("42a", 10)
+	.inspect_err_dbg!()
+	.unwrap_or(0);
+assert_eq!(v, 0);
+# }
+```
+*/
+#[macro_export]
+macro_rules! inspect_err_dbg {
+	($v:expr) => {
+		match $v {
+			Ok(v) => Ok(v),
+			Err(e) => {
+				eprintln!("[{}:{}:{}] Err({:#?})", file!(), line!(), column!(), &e);
+				Err(e)
+			},
+		}
+	};
+}
+
+/**
+Prints the `Ok` variant of a `Result` like [`dbg!`] would
+
+Meant to be used in a postfix context.
+This is the counterpart of [`inspect_err_dbg!`]:
+if the argument is `Ok`, the contained value is printed
+to stderr, together with the location of the invocation.
+The argument is passed through unchanged.
+
+```
+# use postfix_macros::{postfix_macros, inspect_ok_dbg};
+# postfix_macros! {
+let v = u32::from_str_radix("42", 10)
+	.inspect_ok_dbg!()
+	.unwrap_or(0);
+assert_eq!(v, 42);
+# }
+```
+*/
+#[macro_export]
+macro_rules! inspect_ok_dbg {
+	($v:expr) => {
+		match $v {
+			Ok(v) => {
+				eprintln!("[{}:{}:{}] Ok({:#?})", file!(), line!(), column!(), &v);
+				Ok(v)
+			},
+			Err(e) => Err(e),
+		}
+	};
+}
+
+/**
+Evaluates the argument, catching any panics
+
+Meant to be used in a postfix context, to contain
+panicking code inline.
+
+Evaluates the argument inside [`std::panic::catch_unwind`],
+and returns a `Result<T, Box<dyn Any + Send>>`:
+`Ok` with the value of the argument if it didn't panic,
+and `Err` with the panic payload if it did.
+
+Like with the function, the argument must be
+[`UnwindSafe`](std::panic::UnwindSafe).
+If you know better than the compiler,
+pass `AssertUnwindSafe` to the macro to
+wrap the argument into
+[`AssertUnwindSafe`](std::panic::AssertUnwindSafe).
+
+As the argument is evaluated inside a closure,
+control flow like `return` or `?` inside of it
+affects the closure, not the surrounding function.
+
+```
+# use postfix_macros::{postfix_macros, catch_unwind};
+# postfix_macros! {
+let v = vec![1, 2, 3];
+let res = v[1].catch_unwind!();
+assert_eq!(res.ok(), Some(2));
+let res = v[10].catch_unwind!();
+assert!(res.is_err());
+
+let mut count = 0;
+let res = (count += 1).catch_unwind!(AssertUnwindSafe);
+assert!(res.is_ok());
+assert_eq!(count, 1);
+# }
+```
+*/
+#[macro_export]
+macro_rules! catch_unwind {
+	($v:expr) => {
+		std::panic::catch_unwind(|| $v)
+	};
+	($v:expr, AssertUnwindSafe) => {
+		std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| $v))
+	};
+}
+
+/**
+**`if let`** as an expression
+
+Meant to be used in a postfix context, as
+the postfix analog of **`if let`**.
+
+Matches the argument against the pattern.
+If it matches, evaluates to `Some` of the arm's value,
+otherwise evaluates to `None`.
+
+If an `else` arm is given, the macro evaluates
+to the value of the matching arm directly,
+and to the value of the `else` arm otherwise.
+
+If you want to run arbitrary code in the
+non-matching case instead, like `return` or `continue`,
+use the [`match_or!`] macro.
+
+```
+# use postfix_macros::{postfix_macros, if_let};
+# postfix_macros! {
+enum Event {
+	Click { x :u32, y :u32 },
+	Key(char),
+}
+let msg = Event::Click { x :3, y :4 };
+let v = msg.if_let!(Event::Click { x, y } => x * y);
+assert_eq!(v, Some(12));
+
+let msg = Event::Key('a');
+let v = msg.if_let!(Event::Click { x, y } => x * y, else => 0);
+assert_eq!(v, 0);
+# }
+```
+*/
+#[macro_export]
+macro_rules! if_let {
+	($v:expr, $pat:pat => $e:expr $(,)?) => {
+		if let $pat = $v {
+			Some($e)
+		} else {
+			None
+		}
+	};
+	($v:expr, $pat:pat => $e:expr, else => $else:expr $(,)?) => {
+		if let $pat = $v {
+			$e
+		} else {
+			$else
+		}
+	};
+}
+
+/**
+**`match`** on the argument
+
+Meant to be used in a postfix context, as
+the postfix analog of **`match`**.
+
+The argument becomes the scrutinee, and the
+remaining tokens are passed through as the arms.
+As `match` is a keyword, the macro has to be
+imported as `r#match`. The [`postfix_macros!`]
+macro takes care of turning `.match!` into
+an invocation of `r#match!`.
+
+If you only care about one pattern, and want
+to run arbitrary code otherwise, the [`match_or!`]
+macro is more concise.
+
+```
+# use postfix_macros::{postfix_macros, r#match};
+# postfix_macros! {
+let status = 404;
+let res :Result<(), u32> = status.match! {
+	200..=299 => Ok(()),
+	code => Err(code),
+};
+assert_eq!(res, Err(404));
+# }
+```
+*/
+#[macro_export]
+macro_rules! r#match {
+	($v:expr, $($arms:tt)*) => {
+		match $v {
+			$($arms)*
+		}
+	};
+}
+
+/**
+Applies a transformation if a condition holds
+
+Meant to be used in a postfix context, for
+optional steps in builder style chains.
+
+If the condition is `true`, the transformation
+is called with the argument, and the macro evaluates
+to its result. Otherwise, the macro evaluates to
+the argument unchanged.
+
+Instead of a condition, a `let` pattern can be given.
+The transformation is then only applied if the pattern
+matches, and has access to the bindings of the pattern.
+
+```
+# use postfix_macros::{postfix_macros, when};
+# postfix_macros! {
+let verbose = true;
+let suffix :Option<&str> = None;
+let s = String::from("hello")
+	.when!(verbose, |s| s + " world")
+	.when!(let Some(suffix) = suffix, |s| s + suffix);
+assert_eq!(s, "hello world");
+# }
+```
+*/
+#[macro_export]
+macro_rules! when {
+	($v:expr, let $pat:pat = $e:expr, $f:expr $(,)?) => {
+		match $v {
+			v => if let $pat = $e {
+				($f)(v)
+			} else {
+				v
+			},
+		}
+	};
+	($v:expr, $cond:expr, $f:expr $(,)?) => {
+		match $v {
+			v => if $cond {
+				($f)(v)
+			} else {
+				v
+			},
+		}
+	};
+}
+
+/**
+Runs code for its side effects, passing the argument through
+
+Meant to be used in a postfix context, to do
+something with the value of a chain, like recording
+or logging it, without interrupting the chain.
+
+The code can either be given as a closure or the path
+of a function, which is called with a reference to the
+argument, or as a block, inside of which the reference to
+the argument is available under the name `it`.
+Either way, the macro evaluates to the argument.
+
+```
+# use postfix_macros::{postfix_macros, tap};
+# postfix_macros! {
+fn log(s :&str) {
+	eprintln!("got {}", s);
+}
+let mut seen = Vec::new();
+let s = "hello"
+	.to_uppercase()
+	.tap!(|s| seen.push(s.len()))
+	.tap!{ seen.push(it.len() + 1) }
+	.tap!(log);
+assert_eq!(s, "HELLO");
+assert_eq!(seen, [5, 6]);
+# }
+```
+*/
+#[macro_export]
+macro_rules! tap {
+	($v:expr, | $($closure:tt)*) => {
+		match $v {
+			v => {
+				$crate::__private::call_with_ref(&v, | $($closure)*);
+				v
+			},
+		}
+	};
+	($v:expr, || $($closure:tt)*) => {
+		match $v {
+			v => {
+				(|| $($closure)*)();
+				v
+			},
+		}
+	};
+	($v:expr, move $($closure:tt)*) => {
+		match $v {
+			v => {
+				$crate::__private::call_with_ref(&v, move $($closure)*);
+				v
+			},
+		}
+	};
+	($v:expr, $f:path $(,)?) => {
+		match $v {
+			v => {
+				$f(&v);
+				v
+			},
+		}
+	};
+	($v:expr, $($body:tt)*) => {
+		match $v {
+			v => {
+				$crate::__private::implicit_binding!(it (&v) { $($body)* });
+				v
+			},
+		}
+	};
+}
+
+/**
+Passes the argument to a function
+
+Meant to be used in a postfix context, to turn
+nested function calls into left-to-right chains,
+even for free functions that can't be called
+via method syntax.
+
+The function can be given as a path or a closure.
+
+```
+# use postfix_macros::{postfix_macros, pipe};
+fn parse(s :&str) -> Result<u32, std::num::ParseIntError> {
+	s.trim().parse()
+}
+fn validate(v :u32) -> Result<u32, std::num::ParseIntError> {
+	Ok(v.min(100))
+}
+# fn main() -> Result<(), std::num::ParseIntError> {
+# postfix_macros! {
+let raw = " 420 ";
+let v = raw.pipe!(parse)?.pipe!(validate)?.pipe!(|v| v * 2);
+assert_eq!(v, 200);
+# }
+# Ok(())
+# }
+```
+*/
+#[macro_export]
+macro_rules! pipe {
+	($v:expr, $f:expr $(,)?) => {
+		$crate::__private::call($v, $f)
+	};
+}
+
+/**
+Runs a block with access to the argument, then evaluates to the argument
+
+Meant to be used in a postfix context, similar to
+Kotlin's `also` function.
+
+Inside the block, a mutable reference to the
+argument is available under the name `it`.
+A different name can be chosen by prefixing the
+block with `name =>`.
+After the block has been executed, the macro
+evaluates to the (possibly mutated) argument.
+
+If you only need a shared reference, you can use [`tap!`].
+
+```
+# use postfix_macros::{postfix_macros, also};
+# postfix_macros! {
+let v = vec![3, 1, 2]
+	.also!{ it.sort(); it.push(4); }
+	.also!(v => v.retain(|e| e % 2 == 0));
+assert_eq!(v, [2, 4]);
+# }
+```
+*/
+#[macro_export]
+macro_rules! also {
+	($v:expr, $name:ident => $($body:tt)*) => {
+		match $v {
+			mut v => {
+				{
+					let $name = &mut v;
+					$($body)*
+				};
+				v
+			},
+		}
+	};
+	($v:expr, $($body:tt)*) => {
+		match $v {
+			mut v => {
+				$crate::__private::implicit_binding!(it (&mut v) { $($body)* });
+				v
+			},
+		}
+	};
+}
+
+/**
+Binds the argument to a name, and evaluates a body
+
+Meant to be used in a postfix context, as
+the postfix analog of a **`let`** binding
+in expression position.
+
+The macro evaluates to the value of the body.
+Any irrefutable pattern can be used for the binding.
+
+```
+# use postfix_macros::{postfix_macros, with};
+# postfix_macros! {
+let v = vec![1, 2, 3]
+	.with!(v => { let first = v[0]; first + v.len() });
+assert_eq!(v, 4);
+
+let sum = (20, 22).with!((a, b) => a + b);
+assert_eq!(sum, 42);
+# }
+```
+*/
+#[macro_export]
+macro_rules! with {
+	($v:expr, $pat:pat => $($body:tt)*) => {
+		match $v {
+			$pat => {
+				$($body)*
+			},
+		}
+	};
+}
+
+/**
+Mutates the argument, then evaluates to it
+
+Meant to be used in a postfix context, to
+make ad-hoc initialization of types without
+builder APIs chainable.
+
+The argument is bound mutably to the given name,
+then the statements are executed,
+and the macro evaluates to the finished value.
+
+Unlike with [`also!`], the binding is the value
+itself, not a reference to it, so it can
+also be replaced entirely.
+
+```
+# use postfix_macros::{postfix_macros, apply};
+# postfix_macros! {
+let seed = 42;
+let v = Vec::new().apply!(v => {
+	v.reserve(64);
+	v.push(seed);
+});
+assert_eq!(v, [42]);
+assert!(v.capacity() >= 64);
+# }
+```
+*/
+#[macro_export]
+macro_rules! apply {
+	($v:expr, $name:ident => $($body:tt)*) => {
+		match $v {
+			mut $name => {
+				{
+					$($body)*
+				};
+				$name
+			},
+		}
+	};
+}
+
+/**
+Runs cleanup code when the current scope is exited
+
+Meant to be used in a postfix context, in
+statement position, similar to Go's `defer`.
+
+The argument is put into a guard that lives
+until the end of the current scope.
+When the guard gets dropped, the cleanup code gets
+executed with access to the argument.
+
+If the argument is a variable, the guard borrows it,
+so it can still be used for the rest of the scope,
+but not mutated or moved.
+The cleanup code can refer to the borrow under the
+name of the variable, or under the name `it` for `self`.
+Otherwise, the argument is moved into the guard,
+and available to the cleanup code under the name `it`.
+The value of the cleanup code is ignored.
+
+As the guard is dropped at the end of the scope,
+it also runs during unwinding, or if the scope
+is left early via `return`, `break` or `?`.
+
+```
+# use postfix_macros::{postfix_macros, defer};
+# fn main() -> std::io::Result<()> {
+# postfix_macros! {
+let lock_path = std::env::temp_dir().join("postfix-macros-defer-doctest.lock");
+std::fs::write(&lock_path, "")?;
+{
+	lock_path.defer!(std::fs::remove_file(lock_path));
+	assert!(lock_path.exists());
+}
+assert!(!lock_path.exists());
+# }
+# Ok(())
+# }
+```
+*/
+#[macro_export]
+macro_rules! defer {
+	// `self` can't be rebound, so it's matched via a copy of the
+	// ident, keeping the original token for its hygiene
+	(@ident self $v:ident, $($cleanup:tt)*) => {
+		let _guard = $crate::__private::Defer::new(&$v, |v| {
+			let _ = $crate::__private::implicit_binding!(it (v) { $($cleanup)* });
+		});
+	};
+	(@ident $_v:ident $v:ident, $($cleanup:tt)*) => {
+		let _guard = $crate::__private::Defer::new(&$v, |$v| {
+			let _ = { $($cleanup)* };
+		});
+	};
+	($v:ident, $($cleanup:tt)*) => {
+		$crate::defer!(@ident $v $v, $($cleanup)*);
+	};
+	($v:expr, $($cleanup:tt)*) => {
+		let _guard = $crate::__private::Defer::new($v, |v| {
+			let _ = $crate::__private::implicit_binding!(it (v) { $($cleanup)* });
+		});
+	};
+}
+
+/**
+Re-evaluates a fallible argument until it succeeds
+
+Meant to be used in a postfix context.
+
+Evaluates the argument, which must be a `Result`,
+up to the given number of times, until it is `Ok`.
+The macro evaluates to the first `Ok` value, or
+to the last error if all attempts have failed.
+
+Optionally, a `backoff = duration` parameter can be
+passed, with a [`Duration`](std::time::Duration)
+to sleep between attempts. The duration has to be
+an expression like `Duration::from_millis(100)`,
+shorthands like `100ms` are not supported.
+
+As the macro sees the tokens of the argument, the
+argument expression itself is re-evaluated each time.
+Alternatively, a closure literal can be passed as the
+argument, which is then called for each attempt.
+To call any other callable argument, like a variable
+holding a closure, pass `call` before the number of attempts.
+
+```
+# use postfix_macros::{postfix_macros, retry};
+# use std::time::Duration;
+# postfix_macros! {
+let mut calls = 0;
+let mut fetch = || {
+	calls += 1;
+	if calls < 3 { Err(calls) } else { Ok(calls) }
+};
+let res = fetch().retry!(2);
+assert_eq!(res, Err(2));
+let res = (|| fetch()).retry!(3, backoff = Duration::from_millis(1));
+assert_eq!(res, Ok(3));
+let mut attempts = 0;
+let mut connect = || {
+	attempts += 1;
+	if attempts < 2 { Err(attempts) } else { Ok(attempts) }
+};
+let res = connect.retry!(call, 3);
+assert_eq!(res, Ok(2));
+# }
+```
+*/
+#[macro_export]
+macro_rules! retry {
+	(@parse ($($body:tt)*) $n:expr) => {
+		$crate::retry!(@run ($($body)*) $n, None)
+	};
+	(@parse ($($body:tt)*) $n:expr, backoff = $backoff:expr) => {
+		$crate::retry!(@run ($($body)*) $n, Some($backoff))
+	};
+	(@run ($($body:tt)*) $n:expr, $backoff:expr) => {{
+		let attempts :u32 = $n;
+		let mut attempt = 1;
+		loop {
+			match $($body)* {
+				Ok(v) => break Ok(v),
+				Err(e) => {
+					if attempt >= attempts {
+						break Err(e);
+					}
+					attempt += 1;
+					if let Some(backoff) = $backoff {
+						::std::thread::sleep(backoff);
+					}
+				},
+			}
+		}
+	}};
+	({ ( || $($closure:tt)* ) }, $($args:tt)*) => {
+		$crate::retry!(@parse ((|| $($closure)*)()) $($args)*)
+	};
+	({ ( move || $($closure:tt)* ) }, $($args:tt)*) => {
+		$crate::retry!(@parse ((move || $($closure)*)()) $($args)*)
+	};
+	($v:expr, call, $($args:tt)*) => {
+		$crate::retry!(@parse (($v)()) $($args)*)
+	};
+	($v:expr, $($args:tt)*) => {
+		$crate::retry!(@parse ($v) $($args)*)
+	};
+}
+
+/**
+Caches the value of the argument, keyed by the given expression
+
+Meant to be used in a postfix context, to serve
+repeated evaluations of expensive expressions
+in hot paths from a cache.
+
+Each invocation of the macro has its own hidden static cache.
+If the cache contains an entry for the key, the macro evaluates
+to a clone of the cached value, without evaluating the argument.
+Otherwise, the argument is evaluated, and its value is
+stored in the cache before being returned.
+
+The key must implement `Hash`, `Eq`, and `Clone`,
+the value must implement `Clone`.
+Both must be `Send` and `'static`.
+The cache is thread-safe, and not locked during
+evaluation of the argument, so if multiple threads
+miss the cache at the same time, the argument
+might be evaluated multiple times.
+
+Requires Rust 1.70 or later, as it uses [`std::sync::OnceLock`].
+
+```
+# use postfix_macros::{postfix_macros, memoize};
+# postfix_macros! {
+fn expensive(v :u64, calls :&mut u32) -> u64 {
+	*calls += 1;
+	v * v
+}
+let mut calls = 0;
+for key in [3, 4, 3, 3].iter() {
+	let v = expensive(*key, &mut calls).memoize!(*key);
+	assert_eq!(v, key * key);
+}
+assert_eq!(calls, 2);
+# }
+```
+*/
+#[macro_export]
+macro_rules! memoize {
+	($v:expr, $key:expr $(,)?) => {{
+		static CACHE :std::sync::OnceLock<$crate::__private::MemoCache> = std::sync::OnceLock::new();
+		CACHE
+			.get_or_init($crate::__private::MemoCache::default)
+			.get_or_insert_with(($key).clone(), || $v)
+	}};
+}
+
+/**
+Evaluates the argument only once
+
+Meant to be used in a postfix context, for
+initialization code that should only run
+the first time control reaches it.
+
+Each invocation of the macro is backed by its own
+[`std::sync::Once`]. The first time the macro is reached,
+the argument is evaluated and the macro evaluates to
+`Some` of its value. Every time after that, the argument
+is not evaluated, and the macro evaluates to `None`.
+
+As the argument is evaluated inside a closure,
+control flow like `return` or `?` inside of it
+affects the closure, not the surrounding function.
+
+```
+# use postfix_macros::{postfix_macros, once};
+# postfix_macros! {
+let mut inits = 0;
+for i in 0..3 {
+	let v = (inits += 1).once!();
+	assert_eq!(v.is_some(), i == 0);
+}
+assert_eq!(inits, 1);
+# }
+```
+*/
+#[macro_export]
+macro_rules! once {
+	($v:expr) => {{
+		static ONCE :std::sync::Once = std::sync::Once::new();
+		let mut res = None;
+		ONCE.call_once(|| res = Some($v));
+		res
+	}};
+}
+
+/**
+Initializes a static from the argument
+
+Meant to be used in a postfix context, to turn
+an expression into a lazily initialized static.
+
+Declares a static with the given name and type, backed by a
+[`std::sync::OnceLock`]. The first time the macro is reached,
+the argument is evaluated and stored in the static.
+The macro evaluates to a `&'static` reference to the value
+of the static, without evaluating the argument again.
+
+The static is local to the invocation, the name is for
+documentation purposes and error messages.
+If you don't want to name it, use [`once_cell!`].
+
+Requires Rust 1.70 or later.
+
+```
+# use postfix_macros::{postfix_macros, lazy_static};
+# postfix_macros! {
+fn compute_table() -> [u16; 256] {
+	let mut table = [0; 256];
+	for (i, v) in table.iter_mut().enumerate() {
+		*v = (i * i) as u16;
+	}
+	table
+}
+fn table() -> &'static [u16; 256] {
+	compute_table().lazy_static!(TABLE: [u16; 256])
+}
+assert_eq!(table()[12], 144);
+assert!(std::ptr::eq(table(), table()));
+# }
+```
+*/
+#[macro_export]
+macro_rules! lazy_static {
+	($v:expr, $name:ident : $t:ty $(,)?) => {{
+		static $name :std::sync::OnceLock<$t> = std::sync::OnceLock::new();
+		$name.get_or_init(|| $v)
+	}};
+}
+
+/**
+Initializes an anonymous static from the argument
+
+Meant to be used in a postfix context.
+Like [`lazy_static!`], but only requires the type of the static.
+The macro evaluates to a `&'static` reference to the value
+of the static, which is initialized from the argument the
+first time the macro is reached.
+
+Requires Rust 1.70 or later.
+
+```
+# use postfix_macros::{postfix_macros, once_cell};
+# postfix_macros! {
+fn greeting() -> &'static str {
+	["hello", "world"].join(" ").once_cell!(String)
+}
+assert_eq!(greeting(), "hello world");
+# }
+```
+*/
+#[macro_export]
+macro_rules! once_cell {
+	($v:expr, $t:ty $(,)?) => {{
+		static CELL :std::sync::OnceLock<$t> = std::sync::OnceLock::new();
+		CELL.get_or_init(|| $v)
+	}};
+}
+
+/**
+Leaks the argument, evaluating to a `&'static` reference
+
+Meant to be used in a postfix context, for values that
+should live until the end of the program, like
+configuration objects of command line tools or daemons.
+
+Moves the argument into a [`Box`], and leaks it via
+[`Box::leak`]. The memory is never freed.
+If you need a mutable reference, use [`leak_static_mut!`].
+
+```
+# use postfix_macros::{postfix_macros, leak_static};
+# postfix_macros! {
+struct Config {
+	verbose :bool,
+}
+let config = Config { verbose : true };
+let config :&'static Config = config.leak_static!();
+std::thread::spawn(move || assert!(config.verbose)).join().unwrap();
+# }
+```
+*/
+#[macro_export]
+macro_rules! leak_static {
+	($v:expr) => {
+		&*Box::leak(Box::new($v))
+	};
+}
+
+/**
+Leaks the argument, evaluating to a `&'static mut` reference
+
+Meant to be used in a postfix context.
+Like [`leak_static!`], but evaluates
+to a mutable reference.
+
+```
+# use postfix_macros::{postfix_macros, leak_static_mut};
+# postfix_macros! {
+let v :&'static mut Vec<u32> = Vec::new().leak_static_mut!();
+v.push(42);
+assert_eq!(v, &[42]);
+# }
+```
+*/
+#[macro_export]
+macro_rules! leak_static_mut {
+	($v:expr) => {
+		Box::leak(Box::new($v))
+	};
+}
+
+/**
+[`dbg!`] that only prints in debug builds
+
+Meant to be used in a postfix context, to leave
+instrumentation in hot paths.
+
+If debug assertions are enabled, the macro behaves
+like [`dbg!`], printing the argument and passing
+it through. Otherwise, the macro evaluates to the
+argument, without generating any formatting code.
+
+```
+# use postfix_macros::{postfix_macros, dbg_release};
+# postfix_macros! {
+let v = (1..10)
+	.map(|v| v * v)
+	.filter(|v| v % 2 == 0)
+	.dbg_release!()
+	.count();
+assert_eq!(v, 4);
+# }
+```
+*/
+#[macro_export]
+macro_rules! dbg_release {
+	($v:expr) => {{
+		#[cfg(debug_assertions)]
+		let v = dbg!($v);
+		#[cfg(not(debug_assertions))]
+		let v = $v;
+		v
+	}};
+}
+
+/**
+[`dbg!`] with a custom format string
+
+Meant to be used in a postfix context, to make
+debug output in long chains easier to attribute.
+
+Prints the argument to stderr, formatted with the
+given format string, which must be a literal with
+exactly one placeholder for the argument.
+Like [`dbg!`], the output is prefixed with the file,
+line and column of the invocation, and the macro
+evaluates to the argument.
+
+```
+# use postfix_macros::{postfix_macros, dbgf};
+# postfix_macros! {
+let latencies = [12.5, 18.25, 30.0];
+let max = latencies
+	.iter()
+	.fold(0.0f64, |a, b| a.max(*b))
+	.dbgf!("p100 = {:.2}ms");
+assert_eq!(max, 30.0);
+# }
+```
+*/
+#[macro_export]
+macro_rules! dbgf {
+	($v:expr, $fmt:literal $(,)?) => {
+		match $v {
+			v => {
+				eprintln!(concat!("[{}:{}:{}] ", $fmt), file!(), line!(), column!(), &v);
+				v
+			},
+		}
+	};
+}
+
+/**
+[`dbg!`] with pretty formatting and optional truncation
+
+Meant to be used in a postfix context, when
+debugging large nested structs in a chain.
+
+Prints the argument to stderr like [`dbg!`] does,
+using the pretty `{:#?}` format, and evaluates to it.
+If a maximum length is given, the formatted
+output gets truncated to that many characters.
+
+```
+# use postfix_macros::{postfix_macros, pretty_dbg};
+# postfix_macros! {
+let v = vec![[0u8; 4]; 1000]
+	.pretty_dbg!(100)
+	.len();
+assert_eq!(v, 1000);
+# }
+```
+*/
+#[macro_export]
+macro_rules! pretty_dbg {
+	($v:expr) => {
+		match $v {
+			v => {
+				eprintln!("[{}:{}:{}] {} = {:#?}", file!(), line!(), column!(), stringify!($v), &v);
+				v
+			},
+		}
+	};
+	($v:expr, $max_len:expr $(,)?) => {
+		match $v {
+			v => {
+				eprintln!("[{}:{}:{}] {} = {}", file!(), line!(), column!(), stringify!($v),
+					$crate::__private::truncate(format!("{:#?}", &v), $max_len));
+				v
+			},
+		}
+	};
+}
+
+/**
+[`dbg!`] that only prints if a condition holds
+
+Meant to be used in a postfix context, for chains
+that run too often to print every value.
+
+Prints the argument to stderr like [`dbg!`] does if
+the condition is `true`, and evaluates to the argument
+either way. The condition can refer to a reference to
+the argument: if the argument is a variable other than
+`self`, under its name, otherwise under the name `it`.
+
+```
+# use postfix_macros::{postfix_macros, dbg_if};
+# postfix_macros! {
+let packets = vec![vec![0u8; 1500], vec![0u8; 64]];
+for packet in packets {
+	let packet = packet.dbg_if!(packet.len() > 1400);
+	let first = packet.first().dbg_if!(it.is_none());
+	assert!(first.is_some());
+}
+# }
+```
+*/
+#[macro_export]
+macro_rules! dbg_if {
+	(@it $v:expr, $name:expr, $($cond:tt)*) => {
+		match $v {
+			v => {
+				let cond :bool = $crate::__private::implicit_binding!(it (&v) { $($cond)* });
+				if cond {
+					eprintln!("[{}:{}:{}] {} = {:#?}", file!(), line!(), column!(), $name, &v);
+				}
+				v
+			},
+		}
+	};
+	// `self` can't be rebound, so it's matched via a copy of the
+	// ident, keeping the original token for its hygiene
+	(@ident self $v:ident, $($cond:tt)*) => {
+		$crate::dbg_if!(@it $v, "self", $($cond)*)
+	};
+	(@ident $_v:ident $v:ident, $cond:expr $(,)?) => {
+		match $v {
+			v => {
+				let cond :bool = {
+					let $v = &v;
+					$cond
+				};
+				if cond {
+					eprintln!("[{}:{}:{}] {} = {:#?}", file!(), line!(), column!(), stringify!($v), &v);
+				}
+				v
+			},
+		}
+	};
+	($v:ident, $($cond:tt)*) => {
+		$crate::dbg_if!(@ident $v $v, $($cond)*)
+	};
+	($v:expr, $($cond:tt)*) => {
+		$crate::dbg_if!(@it $v, stringify!($v), $($cond)*)
+	};
+}
+
+/**
+Prints each element of an iterator as it flows through
+
+Meant to be used in a postfix context, in the
+middle of iterator chains.
+
+Wraps the iterator in [`Iterator::inspect`], printing
+each element to stderr together with the file,
+line and column of the invocation.
+As iterators are lazy, nothing is printed until
+the iterator is consumed.
+
+```
+# use postfix_macros::{postfix_macros, dbg_each};
+# postfix_macros! {
+let v = ["1", "2", "x", "4"]
+	.iter()
+	.map(|s| s.parse::<u32>())
+	.dbg_each!()
+	.filter_map(Result::ok)
+	.sum::<u32>();
+assert_eq!(v, 7);
+# }
+```
+*/
+#[macro_export]
+macro_rules! dbg_each {
+	($v:expr) => {
+		Iterator::inspect($v, |e| {
+			eprintln!("[{}:{}:{}] {:?}", file!(), line!(), column!(), e);
+		})
+	};
+}
+
+/**
+[`dbg!`] for integers in hexadecimal notation
+
+Meant to be used in a postfix context.
+Prints the argument to stderr in hexadecimal notation,
+formatted with `{:#x}`, together with the file,
+line and column of the invocation like [`dbg!`] does,
+and evaluates to the argument.
+
+See [`bin!`] and [`oct!`] for other bases.
+
+```
+# use postfix_macros::{postfix_macros, hex};
+# postfix_macros! {
+let flags = 0b1010_0101u8;
+let v = (flags & 0xf0).hex!();
+assert_eq!(v, 0xa0);
+# }
+```
+*/
+#[macro_export]
+macro_rules! hex {
+	($v:expr) => {
+		match $v {
+			v => {
+				eprintln!("[{}:{}:{}] {} = {:#x}", file!(), line!(), column!(), stringify!($v), &v);
+				v
+			},
+		}
+	};
+}
+
+/**
+[`dbg!`] for integers in binary notation
+
+Meant to be used in a postfix context.
+Prints the argument to stderr in binary notation,
+formatted with `{:#b}`, together with the file,
+line and column of the invocation like [`dbg!`] does,
+and evaluates to the argument.
+
+```
+# use postfix_macros::{postfix_macros, bin};
+# postfix_macros! {
+let flags = 0b1010_0101u8;
+let v = (flags & 0xf0).bin!();
+assert_eq!(v, 0xa0);
+# }
+```
+*/
+#[macro_export]
+macro_rules! bin {
+	($v:expr) => {
+		match $v {
+			v => {
+				eprintln!("[{}:{}:{}] {} = {:#b}", file!(), line!(), column!(), stringify!($v), &v);
+				v
+			},
+		}
+	};
+}
+
+/**
+[`dbg!`] for integers in octal notation
+
+Meant to be used in a postfix context.
+Prints the argument to stderr in octal notation,
+formatted with `{:#o}`, together with the file,
+line and column of the invocation like [`dbg!`] does,
+and evaluates to the argument.
+
+```
+# use postfix_macros::{postfix_macros, oct};
+# postfix_macros! {
+let flags = 0b1010_0101u8;
+let v = (flags & 0xf0).oct!();
+assert_eq!(v, 0xa0);
+# }
+```
+*/
+#[macro_export]
+macro_rules! oct {
+	($v:expr) => {
+		match $v {
+			v => {
+				eprintln!("[{}:{}:{}] {} = {:#o}", file!(), line!(), column!(), stringify!($v), &v);
+				v
+			},
+		}
+	};
+}
+
+/**
+Prints the bit pattern of an integer
+
+Meant to be used in a postfix context, for
+debugging registers and flags in systems code.
+
+Prints the argument to stderr in binary notation,
+padded to the width of its type, grouped by nibbles,
+and followed by the indices of the bits that are set,
+like `0b0001_1010 (bits 4,3,1)`.
+The output is prefixed with the file, line and column
+of the invocation, like [`dbg!`] does,
+and the macro evaluates to the argument.
+
+```
+# use postfix_macros::{postfix_macros, bits_dbg};
+# postfix_macros! {
+let status = 0b1_1010u8;
+let ready = status.bits_dbg!() & 0b10 != 0;
+assert!(ready);
+# }
+```
+*/
+#[macro_export]
+macro_rules! bits_dbg {
+	($v:expr) => {
+		match $v {
+			v => {
+				let width = std::mem::size_of_val(&v) * 8;
+				eprintln!("[{}:{}:{}] {} = {}", file!(), line!(), column!(), stringify!($v),
+					$crate::__private::bits_repr(&format!("{:0width$b}", v, width = width)));
+				v
+			},
+		}
+	};
+}
+
+/**
+Prints a hex dump of a byte buffer
+
+Meant to be used in a postfix context, for
+debugging binary data in a chain.
+
+Prints the bytes of the argument, which must
+implement `AsRef<[u8]>`, to stderr in the classic
+offset/hex/ASCII format, and evaluates to the argument.
+Like with [`dbg!`], the output is prefixed with the
+file, line and column of the invocation.
+Optionally, the dump can be limited to the
+given number of bytes.
+
+```
+# use postfix_macros::{postfix_macros, hexdump};
+# postfix_macros! {
+let buf = b"Hello world!\n".to_vec();
+let len = buf.hexdump!().len();
+assert_eq!(len, 13);
+let buf = vec![0u8; 4096].hexdump!(64);
+assert_eq!(buf.len(), 4096);
+# }
+```
+*/
+#[macro_export]
+macro_rules! hexdump {
+	($v:expr) => {
+		$crate::hexdump!(@dump $v, None)
+	};
+	($v:expr, $limit:expr $(,)?) => {
+		$crate::hexdump!(@dump $v, Some($limit))
+	};
+	(@dump $v:expr, $limit:expr) => {
+		match $v {
+			v => {
+				let bytes :&[u8] = AsRef::<[u8]>::as_ref(&v);
+				eprint!("[{}:{}:{}] {} ({} bytes):\n{}", file!(), line!(), column!(), stringify!($v),
+					bytes.len(), $crate::__private::hexdump(bytes, $limit));
+				v
+			},
+		}
+	};
+}
+
+/**
+Prints the type of the argument
+
+Meant to be used in a postfix context, when
+fighting type inference in long iterator chains.
+
+Prints the name of the argument's type, as returned by
+[`std::any::type_name`], to stderr, together with
+the file, line and column of the invocation.
+The macro evaluates to the argument.
+If you want to obtain the name instead of printing it,
+use [`type_name_of!`].
+
+```
+# use postfix_macros::{postfix_macros, type_name};
+# postfix_macros! {
+let v = [1u8, 2, 3]
+	.iter()
+	.map(|v| *v as u32)
+	.type_name!()
+	.max();
+assert_eq!(v, Some(3));
+# }
+```
+*/
+#[macro_export]
+macro_rules! type_name {
+	($v:expr) => {
+		match $v {
+			v => {
+				eprintln!("[{}:{}:{}] {}: {}", file!(), line!(), column!(), stringify!($v),
+					$crate::__private::type_name_of(&v));
+				v
+			},
+		}
+	};
+}
+
+/**
+Evaluates to the name of the argument's type
+
+Meant to be used in a postfix context.
+The expression variant of [`type_name!`]:
+evaluates to the `&'static str` returned by
+[`std::any::type_name`] for the argument's type.
+The argument is only borrowed, so
+variables can still be used afterwards.
+
+```
+# use postfix_macros::{postfix_macros, type_name_of};
+# postfix_macros! {
+let v = vec![1u8, 2];
+assert!(v.type_name_of!().ends_with("Vec<u8>"));
+assert_eq!(v.len(), 2);
+# }
+```
+*/
+#[macro_export]
+macro_rules! type_name_of {
+	($v:expr) => {
+		$crate::__private::type_name_of(&$v)
+	};
+}
+
+/**
+Prints the size and alignment of the argument
+
+Meant to be used in a postfix context, for quick
+investigations of memory layout in the middle of
+an expression.
+
+Prints the values of [`std::mem::size_of_val`] and
+[`std::mem::align_of_val`] for the argument to stderr,
+together with the file, line and column of the invocation.
+The macro evaluates to the argument.
+
+```
+# use postfix_macros::{postfix_macros, size_of_val_dbg};
+# postfix_macros! {
+let v = (1u8, 2u32).size_of_val_dbg!();
+assert_eq!(v.1, 2);
+# }
+```
+*/
+#[macro_export]
+macro_rules! size_of_val_dbg {
+	($v:expr) => {
+		match $v {
+			v => {
+				eprintln!("[{}:{}:{}] {}: size {} bytes, align {} bytes", file!(), line!(), column!(),
+					stringify!($v), std::mem::size_of_val(&v), std::mem::align_of_val(&v));
+				v
+			},
+		}
+	};
+}
+
+/**
+Formats the argument via its `Display` impl
+
+Meant to be used in a postfix context, as
+a chainable replacement for `format!("{}", v)`.
+
+See [`fmt_debug!`] and [`fmt_debug_pretty!`]
+for the `Debug` counterparts.
+
+```
+# use postfix_macros::{postfix_macros, fmt_display};
+# postfix_macros! {
+let err = "x".parse::<u8>().unwrap_err();
+let s = err.fmt_display!().to_uppercase();
+assert_eq!(s, "INVALID DIGIT FOUND IN STRING");
+# }
+```
+*/
+#[macro_export]
+macro_rules! fmt_display {
+	($v:expr) => {
+		format!("{}", $v)
+	};
+}
+
+/**
+Formats the argument via its `Debug` impl
+
+Meant to be used in a postfix context, as
+a chainable replacement for `format!("{:?}", v)`.
+
+```
+# use postfix_macros::{postfix_macros, fmt_debug};
+# postfix_macros! {
+let s = Some("hi").fmt_debug!();
+assert_eq!(s, r#"Some("hi")"#);
+# }
+```
+*/
+#[macro_export]
+macro_rules! fmt_debug {
+	($v:expr) => {
+		format!("{:?}", $v)
+	};
+}
+
+/**
+Formats the argument via its `Debug` impl, pretty printed
+
+Meant to be used in a postfix context, as
+a chainable replacement for `format!("{:#?}", v)`.
+
+```
+# use postfix_macros::{postfix_macros, fmt_debug_pretty};
+# postfix_macros! {
+let s = (1, 2).fmt_debug_pretty!();
+assert_eq!(s, "(\n    1,\n    2,\n)");
+# }
+```
+*/
+#[macro_export]
+macro_rules! fmt_debug_pretty {
+	($v:expr) => {
+		format!("{:#?}", $v)
+	};
+}
+
+/**
+Prints the argument to stdout with a format string
+
+Meant to be used in a postfix context, for
+printf-style tracing inside expression chains.
+
+Prints the argument with the given format string,
+where the argument takes the first placeholder,
+followed by any further arguments passed to the macro.
+The macro evaluates to the argument.
+
+See [`eprintln_as!`] for printing to stderr.
+
+```
+# use postfix_macros::{postfix_macros, println_as};
+# postfix_macros! {
+let prices = [3, 4, 5];
+let total :u32 = prices
+	.iter()
+	.sum()
+	.println_as!("total = {} for {} items", prices.len());
+assert_eq!(total, 12);
+# }
+```
+*/
+#[macro_export]
+macro_rules! println_as {
+	($v:expr, $fmt:literal $(, $args:expr)* $(,)?) => {
+		match $v {
+			v => {
+				println!($fmt, &v $(, $args)*);
+				v
+			},
+		}
+	};
+}
+
+/**
+Prints the argument to stderr with a format string
+
+Meant to be used in a postfix context.
+Like [`println_as!`], but prints to stderr.
+
+```
+# use postfix_macros::{postfix_macros, eprintln_as};
+# postfix_macros! {
+let v = u32::from_str_radix("42", 10).eprintln_as!("parsed: {:?}");
+assert_eq!(v, Ok(42));
+# }
+```
+*/
+#[macro_export]
+macro_rules! eprintln_as {
+	($v:expr, $fmt:literal $(, $args:expr)* $(,)?) => {
+		match $v {
+			v => {
+				eprintln!($fmt, &v $(, $args)*);
+				v
+			},
+		}
+	};
+}
+
+/**
+Writes the argument to a writer
+
+Meant to be used in a postfix context, to end
+a chain by writing its value somewhere.
+
+Expands to a [`write!`] invocation with the given writer
+and format string, where the argument takes the first
+placeholder, followed by any further arguments.
+If the first parameter is a format string instead,
+the argument is used as the writer.
+The macro evaluates to the result of [`write!`], so it can
+be chained with `?`. Like with [`write!`], either
+[`std::io::Write`] or [`std::fmt::Write`]
+needs to be in scope.
+
+```
+# use postfix_macros::{postfix_macros, write_to};
+use std::io::Write;
+# fn main() -> std::io::Result<()> {
+# postfix_macros! {
+let mut out = Vec::new();
+let report = "all good";
+report.write_to!(&mut out, "report: {}\n")?;
+out.write_to!("{} checks\n", 3)?;
+assert_eq!(out, b"report: all good\n3 checks\n");
+# }
+# Ok(())
+# }
+```
+*/
+#[macro_export]
+macro_rules! write_to {
+	($w:expr, $fmt:literal $(, $args:expr)* $(,)?) => {
+		write!($w, $fmt $(, $args)*)
+	};
+	($v:expr, $w:expr, $fmt:literal $(, $args:expr)* $(,)?) => {
+		write!($w, $fmt, $v $(, $args)*)
+	};
+}
+
+/**
+Logs the argument at the given `log` level
+
+Implementation detail of [`log_debug!`],
+[`log_info!`], [`log_warn!`], and [`log_error!`].
+*/
+#[cfg(feature = "log")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_value {
+	($level:ident, $v:expr) => {
+		match $v {
+			v => {
+				$crate::__private::log::log!($crate::__private::log::Level::$level, "{:?}", &v);
+				v
+			},
+		}
+	};
+	($level:ident, $v:expr, $msg:literal $(, $args:expr)* $(,)?) => {
+		match $v {
+			v => {
+				$crate::__private::log::log!($crate::__private::log::Level::$level,
+					concat!($msg, ": {:?}") $(, $args)*, &v);
+				v
+			},
+		}
+	};
+}
+
+/**
+Logs the argument at the debug level
+
+Meant to be used in a postfix context, to instrument
+chains with logging via the [`log`](https://docs.rs/log) crate.
+Requires the `log` feature.
+
+Logs the `Debug` representation of the argument, and
+evaluates to the argument. Optionally, a message can be
+given, which is a format string with arguments of its own.
+The argument is appended to the message.
+
+See [`log_info!`], [`log_warn!`], and [`log_error!`]
+for the other levels.
+
+```
+# use postfix_macros::{postfix_macros, log_debug};
+# postfix_macros! {
+let upstream = "api";
+let resp = Some(200)
+	.log_debug!()
+	.log_debug!("{} response", upstream);
+assert_eq!(resp, Some(200));
+# }
+```
+*/
+#[cfg(feature = "log")]
+#[macro_export]
+macro_rules! log_debug {
+	($($t:tt)*) => {
+		$crate::__log_value!(Debug, $($t)*)
+	};
+}
+
+/**
+Logs the argument at the info level
+
+Meant to be used in a postfix context.
+Like [`log_debug!`], but at the info level.
+Requires the `log` feature.
+
+```
+# use postfix_macros::{postfix_macros, log_info};
+# postfix_macros! {
+let v = vec![1, 2, 3].log_info!("loaded items");
+assert_eq!(v.len(), 3);
+# }
+```
+*/
+#[cfg(feature = "log")]
+#[macro_export]
+macro_rules! log_info {
+	($($t:tt)*) => {
+		$crate::__log_value!(Info, $($t)*)
+	};
+}
+
+/**
+Logs the argument at the warn level
+
+Meant to be used in a postfix context.
+Like [`log_debug!`], but at the warn level.
+Requires the `log` feature.
+
+```
+# use postfix_macros::{postfix_macros, log_warn};
+# postfix_macros! {
+let retries = 3.log_warn!("retrying");
+assert_eq!(retries, 3);
+# }
+```
+*/
+#[cfg(feature = "log")]
+#[macro_export]
+macro_rules! log_warn {
+	($($t:tt)*) => {
+		$crate::__log_value!(Warn, $($t)*)
+	};
+}
+
+/**
+Logs the argument at the error level
+
+Meant to be used in a postfix context.
+Like [`log_debug!`], but at the error level.
+Requires the `log` feature.
+
+```
+# use postfix_macros::{postfix_macros, log_error};
+# postfix_macros! {
+let res :Result<(), &str> = Err("disk full");
+let res = res.log_error!("writing {} failed", "cache");
+assert!(res.is_err());
+# }
+```
+*/
+#[cfg(feature = "log")]
+#[macro_export]
+macro_rules! log_error {
+	($($t:tt)*) => {
+		$crate::__log_value!(Error, $($t)*)
+	};
+}
+
+/**
+Emits a `tracing` event recording the argument
+
+Implementation detail of [`trace_val!`] and [`debug_val!`].
+*/
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tracing_value {
+	($level:ident, $v:expr, % $name:ident $(, $msg:literal)? $(,)?) => {
+		match $v {
+			v => {
+				$crate::__private::tracing::event!($crate::__private::tracing::Level::$level,
+					$name = %v $(, $msg)?);
+				v
+			},
+		}
+	};
+	($level:ident, $v:expr, $(?)? $name:ident $(, $msg:literal)? $(,)?) => {
+		match $v {
+			v => {
+				$crate::__private::tracing::event!($crate::__private::tracing::Level::$level,
+					$name = ?v $(, $msg)?);
+				v
+			},
+		}
+	};
+}
+
+/**
+Emits a `tracing` event at the trace level, recording the argument
+
+Meant to be used in a postfix context, to feed structured
+logs from chains via the [`tracing`](https://docs.rs/tracing) crate.
+Requires the `tracing` feature.
+
+The argument is recorded as a field with the given name,
+and the macro evaluates to the argument.
+By default, or if the name is prefixed with `?`,
+the field is recorded via its `Debug` impl.
+If the name is prefixed with `%`, its `Display` impl is used.
+Optionally, a message can be passed after the field name.
+
+See [`debug_val!`] for the debug level.
+
+```
+# use postfix_macros::{postfix_macros, trace_val};
+# postfix_macros! {
+let sum = [1, 2, 3]
+	.iter()
+	.map(|v| v * 2)
+	.max()
+	.trace_val!(max)
+	.unwrap_or(0)
+	.trace_val!(%max_or_zero, "computed maximum");
+assert_eq!(sum, 6);
+# }
+```
+*/
+#[cfg(feature = "tracing")]
+#[macro_export]
+macro_rules! trace_val {
+	($($t:tt)*) => {
+		$crate::__tracing_value!(TRACE, $($t)*)
+	};
+}
+
+/**
+Emits a `tracing` event at the debug level, recording the argument
+
+Meant to be used in a postfix context.
+Like [`trace_val!`], but at the debug level.
+Requires the `tracing` feature.
+
+```
+# use postfix_macros::{postfix_macros, debug_val};
+# postfix_macros! {
+let user_id = "42".debug_val!(%user_id).len();
+assert_eq!(user_id, 2);
+# }
+```
+*/
+#[cfg(feature = "tracing")]
+#[macro_export]
+macro_rules! debug_val {
+	($($t:tt)*) => {
+		$crate::__tracing_value!(DEBUG, $($t)*)
+	};
+}
+
+/**
+Evaluates the argument inside a `tracing` span
+
+Meant to be used in a postfix context, to bring
+span scoping to chains.
+Requires the `tracing` feature.
+
+First evaluates the span expression, then enters
+the span, and evaluates the argument while the span
+is entered. The span is exited afterwards.
+The macro evaluates to the argument.
+
+For futures, entering a span while creating them
+is not enough. Use [`in_span_async!`] instead.
+
+```
+# use postfix_macros::{postfix_macros, in_span};
+use tracing::info_span;
+# postfix_macros! {
+struct Request { id :u32 }
+fn handle_request(req :Request) -> u32 { req.id * 2 }
+
+let req = Request { id : 21 };
+let v = handle_request(req).in_span!(info_span!("handler", id = req.id));
+assert_eq!(v, 42);
+# }
+```
+*/
+#[cfg(feature = "tracing")]
+#[macro_export]
+macro_rules! in_span {
+	($v:expr, $span:expr $(,)?) => {{
+		let span = $span;
+		let _entered = span.enter();
+		$v
+	}};
+}
+
+/**
+Instruments a future with a `tracing` span
+
+Meant to be used in a postfix context.
+The async-aware variant of [`in_span!`]:
+wraps the future via [`tracing::Instrument::instrument`],
+so that the span is entered every time the future is polled.
+Requires the `tracing` feature.
+
+```
+# use postfix_macros::{postfix_macros, in_span_async};
+use tracing::info_span;
+# postfix_macros! {
+async fn fetch(id :u32) -> u32 { id }
+let fut = fetch(42).in_span_async!(info_span!("fetch"));
+# drop(fut);
+# }
+```
+*/
+#[cfg(feature = "tracing")]
+#[macro_export]
+macro_rules! in_span_async {
+	($v:expr, $span:expr $(,)?) => {
+		$crate::__private::tracing::Instrument::instrument($v, $span)
+	};
+}
+
+/**
+Attaches formatted context to an error
+
+Meant to be used in a postfix context, with the
+[`anyhow`](https://docs.rs/anyhow) crate.
+Requires the `anyhow` feature.
+
+Equivalent to calling [`anyhow::Context::with_context`]
+with a closure that formats the given format string
+and arguments. Like with the function, the formatting
+only happens on the error path.
+Works on both `Result` and `Option` arguments.
+
+```
+# use postfix_macros::{postfix_macros, context};
+# fn main() -> anyhow::Result<()> {
+# postfix_macros! {
+let path = std::path::Path::new("/does/not/exist");
+let err = std::fs::read(path).context!("reading {}", path.display()).unwrap_err();
+assert_eq!(err.to_string(), "reading /does/not/exist");
+
+let v = Some(42).context!("no value")?;
+assert_eq!(v, 42);
+# }
+# Ok(())
+# }
+```
+*/
+#[cfg(feature = "anyhow")]
+#[macro_export]
+macro_rules! context {
+	($v:expr, $($fmt:tt)+) => {
+		$crate::__private::anyhow::Context::with_context($v, || format!($($fmt)+))
+	};
+}
+
+/**
+Attaches lazily computed context to an error
+
+Meant to be used in a postfix context, with the
+[`anyhow`](https://docs.rs/anyhow) crate.
+Requires the `anyhow` feature.
+
+A sibling of [`context!`] for cases where building the
+context is costly: takes an arbitrary block, which is
+only evaluated on the error path, and whose value
+is attached to the error as context via
+[`anyhow::Context::with_context`].
+Works on both `Result` and `Option` arguments.
+
+```
+# use postfix_macros::{postfix_macros, with_context};
+# postfix_macros! {
+let state = vec![1, 2, 3];
+let res :Result<(), std::fmt::Error> = Err(std::fmt::Error);
+let err = res
+	.with_context!({
+		let sum :u32 = state.iter().sum();
+		format!("state sum was {}", sum)
+	})
+	.unwrap_err();
+assert_eq!(err.to_string(), "state sum was 6");
+# }
+```
+*/
+#[cfg(feature = "anyhow")]
+#[macro_export]
+macro_rules! with_context {
+	($v:expr, $($body:tt)+) => {
+		$crate::__private::anyhow::Context::with_context($v, || { $($body)+ })
+	};
+}
+
+/**
+Wraps an error with a formatted message
+
+Meant to be used in a postfix context, with the
+[`eyre`](https://docs.rs/eyre) crate.
+Requires the `eyre` feature.
+
+Equivalent to calling [`eyre::WrapErr::wrap_err_with`]
+with a closure that formats the given format string
+and arguments, so the formatting only happens on the
+error path.
+See [`wrap_err_with!`] for wrapping with arbitrary code.
+
+```
+# use postfix_macros::{postfix_macros, wrap_err};
+# postfix_macros! {
+let path = std::path::Path::new("/does/not/exist");
+let err = std::fs::read(path).wrap_err!("reading {}", path.display()).unwrap_err();
+assert_eq!(err.to_string(), "reading /does/not/exist");
+# }
+```
+*/
+#[cfg(feature = "eyre")]
+#[macro_export]
+macro_rules! wrap_err {
+	($v:expr, $($fmt:tt)+) => {
+		$crate::__private::eyre::WrapErr::wrap_err_with($v, || format!($($fmt)+))
+	};
+}
+
+/**
+Wraps an error with a lazily computed message
+
+Meant to be used in a postfix context, with the
+[`eyre`](https://docs.rs/eyre) crate.
+Requires the `eyre` feature.
+
+Takes an arbitrary block, which is only evaluated on the
+error path, and whose value is used to wrap the error via
+[`eyre::WrapErr::wrap_err_with`].
+
+```
+# use postfix_macros::{postfix_macros, wrap_err_with};
+# postfix_macros! {
+let attempts = [3, 5];
+let res :Result<(), std::fmt::Error> = Err(std::fmt::Error);
+let err = res
+	.wrap_err_with!{
+		let total :u32 = attempts.iter().sum();
+		format!("failed after {} attempts", total)
+	}
+	.unwrap_err();
+assert_eq!(err.to_string(), "failed after 8 attempts");
+# }
+```
+*/
+#[cfg(feature = "eyre")]
+#[macro_export]
+macro_rules! wrap_err_with {
+	($v:expr, $($body:tt)+) => {
+		$crate::__private::eyre::WrapErr::wrap_err_with($v, || { $($body)+ })
+	};
+}
+
+/**
+Attaches a backtrace to the error of a `Result`
+
+Meant to be used in a postfix context, to
+find out where an error came from.
+
+If the argument is an `Err`, captures a
+[`std::backtrace::Backtrace`] at the invocation,
+and evaluates to an `Err` of a [`WithBacktrace`]
+containing both the error and the backtrace.
+The backtrace is captured regardless of the
+`RUST_BACKTRACE` environment variable.
+If the argument is `Ok`, it is passed through.
+
+Requires Rust 1.65 or later.
+
+```
+# use postfix_macros::{postfix_macros, backtrace_here};
+# postfix_macros! {
+let res :Result<u8, _> = "x".parse::<u8>();
+let err = res.backtrace_here!().unwrap_err();
+assert_eq!(err.error.to_string(), "invalid digit found in string");
+assert!(err.to_string().starts_with("invalid digit found in string"));
+# }
+```
+*/
+#[macro_export]
+macro_rules! backtrace_here {
+	($v:expr) => {
+		match $v {
+			Ok(v) => Ok(v),
+			Err(e) => Err($crate::WithBacktrace {
+				error : e,
+				backtrace : std::backtrace::Backtrace::force_capture(),
+			}),
+		}
+	};
+}
+
+/**
+An error paired with a backtrace
+
+Produced by the [`backtrace_here!`] macro, where
+the backtrace is a [`std::backtrace::Backtrace`].
+Displays the error, followed by the backtrace.
+*/
+#[derive(Debug)]
+pub struct WithBacktrace<E, B> {
+	/// The original error
+	pub error :E,
+	/// The backtrace captured for the error
+	pub backtrace :B,
+}
+
+impl<E :std::fmt::Display, B :std::fmt::Display> std::fmt::Display for WithBacktrace<E, B> {
+	fn fmt(&self, f :&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}\n\nBacktrace:\n{}", self.error, self.backtrace)
+	}
+}
+
+impl<E, B> std::error::Error for WithBacktrace<E, B>
+where
+	E :std::error::Error + 'static,
+	B :std::fmt::Display + std::fmt::Debug,
+{
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		Some(&self.error)
+	}
+}
+
+/**
+Logs the argument via `defmt` at the given level
+
+Implementation detail of [`defmt_trace!`], [`defmt_debug!`],
+[`defmt_info!`], [`defmt_warn!`], and [`defmt_error!`].
+*/
+#[cfg(feature = "defmt")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __defmt_value {
+	($level:ident, $v:expr) => {
+		match $v {
+			v => {
+				defmt::$level!("{}", &v);
+				v
+			},
+		}
+	};
+	($level:ident, $v:expr, $msg:expr $(,)?) => {
+		match $v {
+			v => {
+				defmt::$level!("{=str}: {}", $msg, &v);
+				v
+			},
+		}
+	};
+}
+
+/**
+[`dbg!`] for embedded targets, via `defmt`
+
+Meant to be used in a postfix context, as the
+`no_std` equivalent of the [`dbg!`] macro.
+Requires the `defmt` feature.
+
+Expands to [`defmt::dbg!`](https://docs.rs/defmt/latest/defmt/macro.dbg.html),
+which logs the argument via its `defmt::Format` impl,
+together with the location of the invocation,
+and evaluates to it.
+As the `defmt` macros refer to the `defmt` crate by name,
+your crate needs to depend on `defmt` as well.
+
+See [`defmt_info!`] and its siblings for
+logging at specific levels.
+
+```
+# use postfix_macros::{postfix_macros, defmt_dbg};
+# postfix_macros! {
+fn read_sensor(raw :u16) -> u16 {
+	(raw >> 4).defmt_dbg!()
+}
+# }
+```
+*/
+#[cfg(feature = "defmt")]
+#[macro_export]
+macro_rules! defmt_dbg {
+	($v:expr) => {
+		defmt::dbg!($v)
+	};
+}
+
+/**
+Logs the argument via `defmt` at the trace level
+
+Meant to be used in a postfix context.
+Like [`defmt_info!`], but at the trace level.
+Requires the `defmt` feature.
+
+```
+# use postfix_macros::{postfix_macros, defmt_trace};
+# postfix_macros! {
+fn poll(state :u8) -> u8 {
+	state.defmt_trace!("state")
+}
+# }
+```
+*/
+#[cfg(feature = "defmt")]
+#[macro_export]
+macro_rules! defmt_trace {
+	($($t:tt)*) => {
+		$crate::__defmt_value!(trace, $($t)*)
+	};
+}
+
+/**
+Logs the argument via `defmt` at the debug level
+
+Meant to be used in a postfix context.
+Like [`defmt_info!`], but at the debug level.
+Requires the `defmt` feature.
+
+```
+# use postfix_macros::{postfix_macros, defmt_debug};
+# postfix_macros! {
+fn checksum(bytes :&[u8]) -> u8 {
+	bytes.iter().fold(0u8, |a, b| a ^ b).defmt_debug!()
+}
+# }
+```
+*/
+#[cfg(feature = "defmt")]
+#[macro_export]
+macro_rules! defmt_debug {
+	($($t:tt)*) => {
+		$crate::__defmt_value!(debug, $($t)*)
+	};
+}
+
+/**
+Logs the argument via `defmt` at the info level
+
+Meant to be used in a postfix context, as the
+`no_std` equivalent of the [`log_info!`] macro
+for embedded targets.
+Requires the `defmt` feature.
+
+Logs the argument via its `defmt::Format` impl,
+optionally prefixed with the given message,
+and evaluates to the argument.
+As the `defmt` macros refer to the `defmt` crate by name,
+your crate needs to depend on `defmt` as well.
+
+See [`defmt_trace!`], [`defmt_debug!`], [`defmt_warn!`],
+and [`defmt_error!`] for the other levels.
+
+```
+# use postfix_macros::{postfix_macros, defmt_info};
+# postfix_macros! {
+fn read_temperature(raw :i16) -> i16 {
+	(raw / 16).defmt_info!("temperature")
+}
+# }
+```
+*/
+#[cfg(feature = "defmt")]
+#[macro_export]
+macro_rules! defmt_info {
+	($($t:tt)*) => {
+		$crate::__defmt_value!(info, $($t)*)
+	};
+}
+
+/**
+Logs the argument via `defmt` at the warn level
+
+Meant to be used in a postfix context.
+Like [`defmt_info!`], but at the warn level.
+Requires the `defmt` feature.
+
+```
+# use postfix_macros::{postfix_macros, defmt_warn};
+# postfix_macros! {
+fn retries(count :u32) -> u32 {
+	count.defmt_warn!("retrying")
+}
+# }
+```
+*/
+#[cfg(feature = "defmt")]
+#[macro_export]
+macro_rules! defmt_warn {
+	($($t:tt)*) => {
+		$crate::__defmt_value!(warn, $($t)*)
+	};
+}
+
+/**
+Logs the argument via `defmt` at the error level
+
+Meant to be used in a postfix context.
+Like [`defmt_info!`], but at the error level.
+Requires the `defmt` feature.
+
+```
+# use postfix_macros::{postfix_macros, defmt_error};
+# postfix_macros! {
+fn check(status :Result<(), u8>) -> Result<(), u8> {
+	status.defmt_error!("status")
+}
+# }
+```
+*/
+#[cfg(feature = "defmt")]
+#[macro_export]
+macro_rules! defmt_error {
+	($($t:tt)*) => {
+		$crate::__defmt_value!(error, $($t)*)
+	};
+}
+
+/**
+Logs the argument to the browser console
+
+Meant to be used in a postfix context, as the
+[`dbg!`] equivalent for `wasm32` targets.
+Requires the `wasm` feature.
+
+Logs the `Debug` representation of the argument via
+[`web_sys::console::log_1`](https://docs.rs/web-sys/latest/web_sys/console/fn.log_1.html),
+optionally prefixed with the given message,
+and evaluates to the argument.
+
+If you want to log the argument as a JavaScript
+value instead, use [`console_log_js!`].
+
+```
+# use postfix_macros::{postfix_macros, console_log};
+# postfix_macros! {
+fn on_click(x :i32, y :i32) -> (i32, i32) {
+	(x, y).console_log!("click at")
+}
+# }
+```
+*/
+#[cfg(feature = "wasm")]
+#[macro_export]
+macro_rules! console_log {
+	($v:expr) => {
+		match $v {
+			v => {
+				$crate::__private::web_sys::console::log_1(
+					&$crate::__private::wasm_bindgen::JsValue::from_str(&format!("{:?}", &v)));
+				v
+			},
+		}
+	};
+	($v:expr, $msg:expr $(,)?) => {
+		match $v {
+			v => {
+				$crate::__private::web_sys::console::log_1(
+					&$crate::__private::wasm_bindgen::JsValue::from_str(&format!("{}: {:?}", $msg, &v)));
+				v
+			},
+		}
+	};
+}
+
+/**
+Logs the argument to the browser console as a JavaScript value
+
+Meant to be used in a postfix context.
+Like [`console_log!`], but converts a clone of the argument
+into a [`JsValue`](https://docs.rs/wasm-bindgen/latest/wasm_bindgen/struct.JsValue.html)
+instead of formatting it, so that it can be
+inspected in the browser's developer tools.
+Requires the `wasm` feature.
+
+```
+# use postfix_macros::{postfix_macros, console_log_js};
+# postfix_macros! {
+fn score(points :f64) -> f64 {
+	(points * 1.5).console_log_js!("score")
+}
+# }
+```
+*/
+#[cfg(feature = "wasm")]
+#[macro_export]
+macro_rules! console_log_js {
+	($v:expr) => {
+		match $v {
+			v => {
+				$crate::__private::web_sys::console::log_1(
+					&$crate::__private::wasm_bindgen::JsValue::from(Clone::clone(&v)));
+				v
+			},
+		}
+	};
+	($v:expr, $msg:expr $(,)?) => {
+		match $v {
+			v => {
+				$crate::__private::web_sys::console::log_2(
+					&$crate::__private::wasm_bindgen::JsValue::from($msg),
+					&$crate::__private::wasm_bindgen::JsValue::from(Clone::clone(&v)));
+				v
+			},
+		}
+	};
+}
+
+#[cfg(feature = "metrics")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __metrics_labels {
+	($($k:ident = $l:expr),* $(,)?) => {
+		vec![$(
+			$crate::__private::metrics::Label::new(stringify!($k), ToString::to_string(&$l))
+		),*]
+	};
+}
+
+/**
+Increments a counter and passes the argument through
+
+Meant to be used in a postfix context.
+Requires the `metrics` feature.
+
+Increments the counter with the given name by one,
+using the [metrics](https://docs.rs/metrics) crate.
+Labels can be given as `key = value` pairs, where the values
+are anything implementing `Display`.
+Evaluates to the argument.
+
+```
+# use postfix_macros::{postfix_macros, count_metric};
+# postfix_macros! {
+fn handle(method :&str, body :String) -> String {
+	body.count_metric!("requests_total", method = method)
+}
+# }
+```
+*/
+#[cfg(feature = "metrics")]
+#[macro_export]
+macro_rules! count_metric {
+	($v:expr, $name:expr $(, $($labels:tt)*)?) => {
+		match $v {
+			v => {
+				$crate::__private::metrics::counter!($name,
+					$crate::__metrics_labels!($($($labels)*)?)).increment(1);
+				v
+			},
+		}
+	};
+}
+
+/**
+Sets a gauge to the argument
+
+Meant to be used in a postfix context.
+Requires the `metrics` feature.
+
+Sets the gauge with the given name to a clone of the argument,
+using the [metrics](https://docs.rs/metrics) crate.
+Takes labels like [`count_metric!`].
+Evaluates to the argument.
+
+```
+# use postfix_macros::{postfix_macros, gauge_metric};
+# postfix_macros! {
+fn queue_len(queue :&[u32]) -> u32 {
+	(queue.len() as u32).gauge_metric!("queue_length", queue = "jobs")
+}
+# }
+```
+*/
+#[cfg(feature = "metrics")]
+#[macro_export]
+macro_rules! gauge_metric {
+	($v:expr, $name:expr $(, $($labels:tt)*)?) => {
+		match $v {
+			v => {
+				$crate::__private::metrics::gauge!($name,
+					$crate::__metrics_labels!($($($labels)*)?)).set(Clone::clone(&v));
+				v
+			},
+		}
+	};
+}
+
+/**
+Records the argument in a histogram
+
+Meant to be used in a postfix context.
+Requires the `metrics` feature.
+
+Records a clone of the argument in the histogram with the given name,
+using the [metrics](https://docs.rs/metrics) crate.
+Takes labels like [`count_metric!`].
+Evaluates to the argument.
+
+```
+# use postfix_macros::{postfix_macros, histogram_metric};
+# postfix_macros! {
+fn finish(path :&str, latency_ms :f64) -> f64 {
+	latency_ms.histogram_metric!("request_latency", route = path)
+}
+# }
+```
+*/
+#[cfg(feature = "metrics")]
+#[macro_export]
+macro_rules! histogram_metric {
+	($v:expr, $name:expr $(, $($labels:tt)*)?) => {
+		match $v {
+			v => {
+				$crate::__private::metrics::histogram!($name,
+					$crate::__metrics_labels!($($($labels)*)?)).record(Clone::clone(&v));
+				v
+			},
+		}
+	};
+}
+
+/**
+Reports the error of a `Result` to the registered error reporter
+
+Meant to be used in a postfix context, to route
+errors to a central place, like an error tracking service,
+without boilerplate at every call site.
+
+If the argument is an `Err`, calls the reporter registered
+via [`set_postfix_error_reporter!`] with a reference to the error
+and the [`ErrorLocation`] of the invocation.
+If no reporter was registered, nothing happens.
+Evaluates to the argument.
+
+Requires Rust 1.70 or later.
+
+```
+# use postfix_macros::{postfix_macros, report_err, set_postfix_error_reporter};
+# postfix_macros! {
+set_postfix_error_reporter!(|err, loc| {
+	eprintln!("[{}:{}] reported {:?}", loc.file, loc.line, err);
+});
+
+let res :Result<u8, _> = "x".parse::<u8>();
+assert!(res.report_err!().is_err());
+# }
+```
+*/
+#[macro_export]
+macro_rules! report_err {
+	($v:expr) => {
+		match $v {
+			v => {
+				if let Err(e) = &v {
+					$crate::__private::report_error(e, &$crate::ErrorLocation {
+						file : file!(),
+						line : line!(),
+						column : column!(),
+					});
+				}
+				v
+			},
+		}
+	};
+}
+
+/**
+Registers the error reporter used by [`report_err!`]
+
+Takes a function or non-capturing closure of type [`ErrorReporter`].
+The reporter can only be registered once,
+later registrations are ignored.
+Evaluates to `true` if the reporter was registered.
+
+Requires Rust 1.70 or later.
+
+```
+# use postfix_macros::{set_postfix_error_reporter, ErrorLocation};
+fn reporter(err :&dyn std::fmt::Debug, loc :&ErrorLocation) {
+	eprintln!("error at {}:{}: {:?}", loc.file, loc.line, err);
+}
+assert!(set_postfix_error_reporter!(reporter));
+assert!(!set_postfix_error_reporter!(|_, _| {}));
+```
+*/
+#[macro_export]
+macro_rules! set_postfix_error_reporter {
+	($f:expr $(,)?) => {
+		$crate::__private::set_error_reporter($f)
+	};
+}
+
+/**
+A function receiving the errors reported by [`report_err!`]
+
+Registered via [`set_postfix_error_reporter!`].
+*/
+pub type ErrorReporter = fn(&dyn std::fmt::Debug, &ErrorLocation);
+
+/**
+The source location of a [`report_err!`] invocation
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorLocation {
+	/// The file, as given by [`file!`]
+	pub file :&'static str,
+	/// The line, as given by [`line!`]
+	pub line :u32,
+	/// The column, as given by [`column!`]
+	pub column :u32,
+}
+
+/**
+Serializes the argument, panicking on failure
+
+Implementation detail of the serialization macros
+like [`to_json!`].
+*/
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __serialize {
+	($res:expr, $format:expr) => {
+		match $res {
+			Ok(s) => s,
+			Err(e) => panic!("failed to serialize to {}: {}", $format, e),
+		}
+	};
+}
+
+/**
+Serializes the argument to a JSON `String`
+
+Meant to be used in a postfix context.
+Requires the `serde_json` feature.
+
+Serializes a reference to the argument via
+[`serde_json::to_string`](https://docs.rs/serde_json/latest/serde_json/fn.to_string.html),
+panicking if serialization fails.
+For a `Result` returning version, use [`try_to_json!`].
+For pretty printed output, use [`to_json_pretty!`].
+
+```
+# use postfix_macros::{postfix_macros, to_json};
+# postfix_macros! {
+let payload = vec![(1, "one"), (2, "two")];
+assert_eq!(payload.to_json!(), r#"[[1,"one"],[2,"two"]]"#);
+# }
+```
+*/
+#[cfg(feature = "serde_json")]
+#[macro_export]
+macro_rules! to_json {
+	($v:expr) => {
+		$crate::__serialize!($crate::__private::serde_json::to_string(&$v), "JSON")
+	};
+}
+
+/**
+Serializes the argument to a pretty printed JSON `String`
+
+Meant to be used in a postfix context.
+Requires the `serde_json` feature.
+
+Like [`to_json!`], but uses
+[`serde_json::to_string_pretty`](https://docs.rs/serde_json/latest/serde_json/fn.to_string_pretty.html).
+For a `Result` returning version, use [`try_to_json_pretty!`].
+
+```
+# use postfix_macros::{postfix_macros, to_json_pretty};
+# postfix_macros! {
+let payload = vec![1, 2];
+assert_eq!(payload.to_json_pretty!(), "[\n  1,\n  2\n]");
+# }
+```
+*/
+#[cfg(feature = "serde_json")]
+#[macro_export]
+macro_rules! to_json_pretty {
+	($v:expr) => {
+		$crate::__serialize!($crate::__private::serde_json::to_string_pretty(&$v), "JSON")
+	};
+}
+
+/**
+Serializes the argument to a JSON `String`, returning a `Result`
+
+Meant to be used in a postfix context.
+Requires the `serde_json` feature.
+
+Like [`to_json!`], but evaluates to a
+`Result<String, serde_json::Error>` instead of panicking.
+
+```
+# use postfix_macros::{postfix_macros, try_to_json};
+# postfix_macros! {
+# fn main() -> Result<(), Box<dyn std::error::Error>> {
+let json = Some(42).try_to_json!()?;
+assert_eq!(json, "42");
+# Ok(())
+# }
+# }
+```
+*/
+#[cfg(feature = "serde_json")]
+#[macro_export]
+macro_rules! try_to_json {
+	($v:expr) => {
+		$crate::__private::serde_json::to_string(&$v)
+	};
+}
+
+/**
+Serializes the argument to a pretty printed JSON `String`, returning a `Result`
+
+Meant to be used in a postfix context.
+Requires the `serde_json` feature.
+
+Like [`to_json_pretty!`], but evaluates to a
+`Result<String, serde_json::Error>` instead of panicking.
+
+```
+# use postfix_macros::{postfix_macros, try_to_json_pretty};
+# postfix_macros! {
+let json = (1, 2).try_to_json_pretty!().unwrap();
+assert_eq!(json, "[\n  1,\n  2\n]");
+# }
+```
+*/
+#[cfg(feature = "serde_json")]
+#[macro_export]
+macro_rules! try_to_json_pretty {
+	($v:expr) => {
+		$crate::__private::serde_json::to_string_pretty(&$v)
+	};
+}
+
+/**
+Serializes the argument to a YAML `String`
+
+Meant to be used in a postfix context.
+Requires the `yaml` feature.
+
+Serializes a reference to the argument via
+[`serde_yaml::to_string`](https://docs.rs/serde_yaml/latest/serde_yaml/fn.to_string.html),
+panicking if serialization fails.
+For a `Result` returning version, use [`try_to_yaml!`].
+
+```
+# use postfix_macros::{postfix_macros, to_yaml};
+# postfix_macros! {
+let ports = vec![80, 443];
+assert_eq!(ports.to_yaml!(), "- 80\n- 443\n");
+# }
+```
+*/
+#[cfg(feature = "yaml")]
+#[macro_export]
+macro_rules! to_yaml {
+	($v:expr) => {
+		$crate::__serialize!($crate::__private::serde_yaml::to_string(&$v), "YAML")
+	};
+}
+
+/**
+Serializes the argument to a YAML `String`, returning a `Result`
+
+Meant to be used in a postfix context.
+Requires the `yaml` feature.
+
+Like [`to_yaml!`], but evaluates to a
+`Result<String, serde_yaml::Error>` instead of panicking.
+
+```
+# use postfix_macros::{postfix_macros, try_to_yaml};
+# postfix_macros! {
+let yaml = ("a", 1).try_to_yaml!().unwrap();
+assert_eq!(yaml, "- a\n- 1\n");
+# }
+```
+*/
+#[cfg(feature = "yaml")]
+#[macro_export]
+macro_rules! try_to_yaml {
+	($v:expr) => {
+		$crate::__private::serde_yaml::to_string(&$v)
+	};
+}
+
+/**
+Serializes the argument to a TOML `String`
+
+Meant to be used in a postfix context.
+Requires the `toml` feature.
+
+Serializes a reference to the argument via
+[`toml::to_string`](https://docs.rs/toml/latest/toml/fn.to_string.html),
+panicking if serialization fails, e.g. because
+the argument doesn't serialize to a table.
+For a `Result` returning version, use [`try_to_toml!`].
+For pretty printed output, use [`to_toml_pretty!`].
+
+```
+# use postfix_macros::{postfix_macros, to_toml};
+# postfix_macros! {
+let mut config = std::collections::BTreeMap::new();
+config.insert("port", 8080);
+assert_eq!(config.to_toml!(), "port = 8080\n");
+# }
+```
+*/
+#[cfg(feature = "toml")]
+#[macro_export]
+macro_rules! to_toml {
+	($v:expr) => {
+		$crate::__serialize!($crate::__private::toml::to_string(&$v), "TOML")
+	};
+}
+
+/**
+Serializes the argument to a pretty printed TOML `String`
+
+Meant to be used in a postfix context.
+Requires the `toml` feature.
+
+Like [`to_toml!`], but uses
+[`toml::to_string_pretty`](https://docs.rs/toml/latest/toml/fn.to_string_pretty.html).
+For a `Result` returning version, use [`try_to_toml_pretty!`].
+
+```
+# use postfix_macros::{postfix_macros, to_toml_pretty};
+# postfix_macros! {
+let mut config = std::collections::BTreeMap::new();
+config.insert("hosts", vec!["a", "b"]);
+println!("{}", config.to_toml_pretty!());
+# }
+```
+*/
+#[cfg(feature = "toml")]
+#[macro_export]
+macro_rules! to_toml_pretty {
+	($v:expr) => {
+		$crate::__serialize!($crate::__private::toml::to_string_pretty(&$v), "TOML")
+	};
+}
+
+/**
+Serializes the argument to a TOML `String`, returning a `Result`
+
+Meant to be used in a postfix context.
+Requires the `toml` feature.
+
+Like [`to_toml!`], but evaluates to a
+`Result<String, toml::ser::Error>` instead of panicking.
+
+```
+# use postfix_macros::{postfix_macros, try_to_toml};
+# postfix_macros! {
+assert!(42.try_to_toml!().is_err());
+# }
+```
+*/
+#[cfg(feature = "toml")]
+#[macro_export]
+macro_rules! try_to_toml {
+	($v:expr) => {
+		$crate::__private::toml::to_string(&$v)
+	};
+}
+
+/**
+Serializes the argument to a pretty printed TOML `String`, returning a `Result`
+
+Meant to be used in a postfix context.
+Requires the `toml` feature.
+
+Like [`to_toml_pretty!`], but evaluates to a
+`Result<String, toml::ser::Error>` instead of panicking.
+
+```
+# use postfix_macros::{postfix_macros, try_to_toml_pretty};
+# postfix_macros! {
+let mut config = std::collections::BTreeMap::new();
+config.insert("debug", true);
+assert_eq!(config.try_to_toml_pretty!().unwrap(), "debug = true\n");
+# }
+```
+*/
+#[cfg(feature = "toml")]
+#[macro_export]
+macro_rules! try_to_toml_pretty {
+	($v:expr) => {
+		$crate::__private::toml::to_string_pretty(&$v)
+	};
+}
+
+/**
+Parses JSON, falling back to a default value on failure
+
+Meant to be used in a postfix context.
+Requires the `serde_json` feature.
+
+Parses the argument, which can be anything implementing
+`AsRef<[u8]>` like `&str`, `String` or `&[u8]`, via
+[`serde_json::from_slice`](https://docs.rs/serde_json/latest/serde_json/fn.from_slice.html)
+into the type of the fallback value.
+If parsing fails, evaluates to the fallback,
+which is only evaluated in that case.
+
+If a message is given as second parameter, parse errors
+are printed to stderr together with the location
+of the invocation and the message.
+
+```
+# use postfix_macros::{postfix_macros, from_json_or};
+# postfix_macros! {
+let ports :Vec<u16> = "[80, 443]".from_json_or!(Vec::new());
+assert_eq!(ports, [80, 443]);
+
+let body = b"{ oops".to_vec();
+let ports :Vec<u16> = body.from_json_or!(vec![8080], "loading ports");
+assert_eq!(ports, [8080]);
+# }
+```
+*/
+#[cfg(feature = "serde_json")]
+#[macro_export]
+macro_rules! from_json_or {
+	($v:expr, $fallback:expr) => {
+		match $crate::__private::serde_json::from_slice(AsRef::<[u8]>::as_ref(&$v)) {
+			Ok(v) => v,
+			Err(_) => $fallback,
+		}
+	};
+	($v:expr, $fallback:expr, $msg:expr $(,)?) => {
+		match $crate::__private::serde_json::from_slice(AsRef::<[u8]>::as_ref(&$v)) {
+			Ok(v) => v,
+			Err(e) => {
+				eprintln!("[{}:{}:{}] {}: {}", file!(), line!(), column!(), $msg, e);
+				$fallback
+			},
+		}
+	};
+}
+
+/**
+Serializes the argument to a RON `String`
+
+Meant to be used in a postfix context.
+Requires the `ron` feature.
+
+Serializes a reference to the argument via
+[`ron::to_string`](https://docs.rs/ron/latest/ron/ser/fn.to_string.html),
+panicking if serialization fails.
+For a `Result` returning version, use [`try_to_ron!`].
+For pretty printed output, use [`to_ron_pretty!`].
+
+```
+# use postfix_macros::{postfix_macros, to_ron};
+# postfix_macros! {
+let spawn = (Some("goblin"), [1.5, 2.0]);
+assert_eq!(spawn.to_ron!(), r#"(Some("goblin"),(1.5,2.0))"#);
+# }
+```
+*/
+#[cfg(feature = "ron")]
+#[macro_export]
+macro_rules! to_ron {
+	($v:expr) => {
+		$crate::__serialize!($crate::__private::ron::to_string(&$v), "RON")
+	};
+}
+
+/**
+Serializes the argument to a pretty printed RON `String`
+
+Meant to be used in a postfix context.
+Requires the `ron` feature.
+
+Like [`to_ron!`], but uses
+[`ron::ser::to_string_pretty`](https://docs.rs/ron/latest/ron/ser/fn.to_string_pretty.html)
+with the default `PrettyConfig`.
+For a `Result` returning version, use [`try_to_ron_pretty!`].
+
+```
+# use postfix_macros::{postfix_macros, to_ron_pretty};
+# postfix_macros! {
+let levels = vec!["intro", "cave"];
+println!("{}", levels.to_ron_pretty!());
+# }
+```
+*/
+#[cfg(feature = "ron")]
+#[macro_export]
+macro_rules! to_ron_pretty {
+	($v:expr) => {
+		$crate::__serialize!($crate::__private::ron::ser::to_string_pretty(&$v,
+			$crate::__private::ron::ser::PrettyConfig::default()), "RON")
+	};
+}
+
+/**
+Serializes the argument to a RON `String`, returning a `Result`
+
+Meant to be used in a postfix context.
+Requires the `ron` feature.
+
+Like [`to_ron!`], but evaluates to a
+`Result<String, ron::Error>` instead of panicking.
+
+```
+# use postfix_macros::{postfix_macros, try_to_ron};
+# postfix_macros! {
+assert_eq!((1, "a").try_to_ron!().unwrap(), r#"(1,"a")"#);
+# }
+```
+*/
+#[cfg(feature = "ron")]
+#[macro_export]
+macro_rules! try_to_ron {
+	($v:expr) => {
+		$crate::__private::ron::to_string(&$v)
+	};
+}
+
+/**
+Serializes the argument to a pretty printed RON `String`, returning a `Result`
+
+Meant to be used in a postfix context.
+Requires the `ron` feature.
+
+Like [`to_ron_pretty!`], but evaluates to a
+`Result<String, ron::Error>` instead of panicking.
+
+```
+# use postfix_macros::{postfix_macros, try_to_ron_pretty};
+# postfix_macros! {
+assert!(vec![1, 2].try_to_ron_pretty!().is_ok());
+# }
+```
+*/
+#[cfg(feature = "ron")]
+#[macro_export]
+macro_rules! try_to_ron_pretty {
+	($v:expr) => {
+		$crate::__private::ron::ser::to_string_pretty(&$v,
+			$crate::__private::ron::ser::PrettyConfig::default())
+	};
+}
+
+/**
+Encodes the argument as base64
+
+Meant to be used in a postfix context.
+Requires the `base64` feature.
+
+Encodes the argument, which can be anything
+implementing `AsRef<[u8]>`, into a base64 `String`.
+
+Uses the standard alphabet with padding by default.
+Another engine can be chosen by passing the name of one of the
+[general purpose engines](https://docs.rs/base64/latest/base64/engine/general_purpose/index.html),
+like `URL_SAFE_NO_PAD`, or any
+[`Engine`](https://docs.rs/base64/latest/base64/engine/trait.Engine.html)
+via `engine = <expr>`.
+
+```
+# use postfix_macros::{postfix_macros, base64_enc};
+# postfix_macros! {
+assert_eq!("hello".base64_enc!(), "aGVsbG8=");
+assert_eq!(vec![0xfb, 0xff].base64_enc!(URL_SAFE_NO_PAD), "-_8");
+# }
+```
+*/
+#[cfg(feature = "base64")]
+#[macro_export]
+macro_rules! base64_enc {
+	($v:expr) => {
+		$crate::base64_enc!($v, STANDARD)
+	};
+	($v:expr, engine = $engine:expr $(,)?) => {
+		$crate::__private::base64::Engine::encode(&$engine, AsRef::<[u8]>::as_ref(&$v))
+	};
+	($v:expr, $engine:ident $(,)?) => {
+		$crate::base64_enc!($v,
+			engine = $crate::__private::base64::engine::general_purpose::$engine)
+	};
+}
+
+/**
+Decodes the argument from base64
+
+Meant to be used in a postfix context.
+Requires the `base64` feature.
+
+Decodes the argument, which can be anything
+implementing `AsRef<[u8]>` like `&str`, into a
+`Result<Vec<u8>, base64::DecodeError>`.
+
+Takes the same engine parameters as [`base64_enc!`].
+
+```
+# use postfix_macros::{postfix_macros, base64_dec};
+# postfix_macros! {
+assert_eq!("aGVsbG8=".base64_dec!().unwrap(), b"hello");
+assert_eq!("-_8".base64_dec!(URL_SAFE_NO_PAD).unwrap(), [0xfb, 0xff]);
+assert!("not base64!".base64_dec!().is_err());
+# }
+```
+*/
+#[cfg(feature = "base64")]
+#[macro_export]
+macro_rules! base64_dec {
+	($v:expr) => {
+		$crate::base64_dec!($v, STANDARD)
+	};
+	($v:expr, engine = $engine:expr $(,)?) => {
+		$crate::__private::base64::Engine::decode(&$engine, AsRef::<[u8]>::as_ref(&$v))
+	};
+	($v:expr, $engine:ident $(,)?) => {
+		$crate::base64_dec!($v,
+			engine = $crate::__private::base64::engine::general_purpose::$engine)
+	};
+}
+
+/**
+Hashes the argument with the given hash function
+
+Meant to be used in a postfix context.
+Requires the `digest` feature.
+
+Takes the type of a hash function implementing
+[`digest::Digest`](https://docs.rs/digest/latest/digest/trait.Digest.html),
+like `sha2::Sha256`, and hashes the argument, which can be
+anything implementing `AsRef<[u8]>`.
+Evaluates to the digest.
+For a hex `String`, use [`digest_hex!`].
+
+```
+# use postfix_macros::{postfix_macros, digest};
+use sha2::Sha256;
+# postfix_macros! {
+let hash = "hello".digest!(Sha256);
+assert_eq!(hash.len(), 32);
+assert_eq!(hash[..4], [0x2c, 0xf2, 0x4d, 0xba]);
+# }
+```
+*/
+#[cfg(feature = "digest")]
+#[macro_export]
+macro_rules! digest {
+	($v:expr, $hash:ty $(,)?) => {
+		<$hash as $crate::__private::digest::Digest>::digest(AsRef::<[u8]>::as_ref(&$v))
+	};
+}
+
+/**
+Hashes the argument with the given hash function into a hex `String`
+
+Meant to be used in a postfix context.
+Requires the `digest` feature.
+
+Like [`digest!`], but evaluates to the
+digest formatted as lowercase hex.
+
+```
+# use postfix_macros::{postfix_macros, digest_hex};
+use sha2::Sha256;
+# postfix_macros! {
+let data = b"hello".to_vec();
+assert_eq!(data.digest_hex!(Sha256),
+	"2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824");
+# }
+```
+*/
+#[cfg(feature = "digest")]
+#[macro_export]
+macro_rules! digest_hex {
+	($v:expr, $hash:ty $(,)?) => {
+		$crate::__private::hex_lower(&$crate::digest!($v, $hash))
+	};
+}
+
+/**
+Formats a byte count in a human readable way
+
+Meant to be used in a postfix context.
+
+Converts the argument, an unsigned integer of up
+to 64 bits, into a `String` like `1.4 GiB`, using binary
+units based on powers of 1024 by default.
+Pass `decimal` to use units based on powers of 1000
+instead, like `1.5 GB`, or `binary` to be explicit.
+Integer literals need a type suffix, as the macro
+accepts multiple integer types.
+
+```
+# use postfix_macros::{postfix_macros, pretty_bytes};
+# postfix_macros! {
+let file_len :u64 = 1_503_238_553;
+assert_eq!(file_len.pretty_bytes!(), "1.4 GiB");
+assert_eq!(file_len.pretty_bytes!(decimal), "1.5 GB");
+assert_eq!(512u32.pretty_bytes!(), "512 B");
+# }
+```
+
+Signed integers and floats are rejected, instead
+of being converted to a wrong byte count:
+
+```compile_fail
+# use postfix_macros::{postfix_macros, pretty_bytes};
+# postfix_macros! {
+let delta :i64 = -1;
+delta.pretty_bytes!();
+# }
+```
+*/
+#[macro_export]
+macro_rules! pretty_bytes {
+	($v:expr) => {
+		$crate::pretty_bytes!($v, binary)
+	};
+	($v:expr, binary $(,)?) => {
+		$crate::__private::pretty_bytes($crate::__private::ByteCount::byte_count($v), 1024,
+			&["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"])
+	};
+	($v:expr, decimal $(,)?) => {
+		$crate::__private::pretty_bytes($crate::__private::ByteCount::byte_count($v), 1000,
+			&["B", "kB", "MB", "GB", "TB", "PB", "EB"])
+	};
+}
+
+/**
+Puts the argument into a `Box`
+
+Meant to be used in a postfix context.
+Evaluates to `Box::new(arg)`.
+To obtain a trait object, use [`boxed_dyn!`].
+
+```
+# use postfix_macros::{postfix_macros, boxed};
+# postfix_macros! {
+let list = (1, None).boxed!();
+assert_eq!(*list, (1, None::<u8>));
+# }
+```
+*/
+#[macro_export]
+macro_rules! boxed {
+	($v:expr) => {
+		Box::new($v)
+	};
+}
+
+/**
+Puts the argument into a `Box` of a trait object
+
+Meant to be used in a postfix context.
+Takes the trait, including any additional bounds
+like `Send`, and evaluates to a `Box<dyn Trait>`
+containing the argument.
+
+```
+# use postfix_macros::{postfix_macros, boxed_dyn};
+# postfix_macros! {
+let handlers = vec![
+	(|x| x + 1).boxed_dyn!(Fn(u32) -> u32),
+	(|x| x * 2).boxed_dyn!(Fn(u32) -> u32),
+];
+assert_eq!(handlers.iter().map(|h| h(3)).sum::<u32>(), 10);
+
+let err = "oops".to_string().boxed_dyn!(std::fmt::Display + Send);
+assert_eq!(err.to_string(), "oops");
+# }
+```
+*/
+#[macro_export]
+macro_rules! boxed_dyn {
+	($v:expr, $($tr:tt)+) => {{
+		let b :Box<dyn $($tr)+> = Box::new($v);
+		b
+	}};
+}
+
+/**
+Puts the argument into an `Rc`
+
+Meant to be used in a postfix context.
+Evaluates to `std::rc::Rc::new(arg)`.
+
+```
+# use postfix_macros::{postfix_macros, rc};
+# postfix_macros! {
+let shared = vec![1, 2].rc!();
+let other = shared.clone();
+assert_eq!(std::rc::Rc::strong_count(&other), 2);
+# }
+```
+*/
+#[macro_export]
+macro_rules! rc {
+	($v:expr) => {
+		std::rc::Rc::new($v)
+	};
+}
+
+/**
+Puts the argument into an `Arc`
+
+Meant to be used in a postfix context.
+Evaluates to `std::sync::Arc::new(arg)`.
+
+```
+# use postfix_macros::{postfix_macros, arc};
+# postfix_macros! {
+let config = String::from("verbose").arc!();
+let handle = {
+	let config = config.clone();
+	std::thread::spawn(move || config.len())
+};
+assert_eq!(handle.join().unwrap(), 7);
+# }
+```
+*/
+#[macro_export]
+macro_rules! arc {
+	($v:expr) => {
+		std::sync::Arc::new($v)
+	};
+}
+
+/**
+Puts the argument into a `Cell`
+
+Meant to be used in a postfix context.
+Evaluates to `std::cell::Cell::new(arg)`.
+
+```
+# use postfix_macros::{postfix_macros, cell};
+# postfix_macros! {
+let counter = 0.cell!();
+counter.set(counter.get() + 1);
+assert_eq!(counter.get(), 1);
+# }
+```
+*/
+#[macro_export]
+macro_rules! cell {
+	($v:expr) => {
+		std::cell::Cell::new($v)
+	};
+}
+
+/**
+Puts the argument into a `RefCell`
+
+Meant to be used in a postfix context.
+Evaluates to `std::cell::RefCell::new(arg)`.
+
+```
+# use postfix_macros::{postfix_macros, refcell};
+# postfix_macros! {
+let log = Vec::new().refcell!();
+log.borrow_mut().push("started");
+assert_eq!(*log.borrow(), ["started"]);
+# }
+```
+*/
+#[macro_export]
+macro_rules! refcell {
+	($v:expr) => {
+		std::cell::RefCell::new($v)
+	};
+}
+
+/**
+Puts the argument into a `Mutex`
+
+Meant to be used in a postfix context.
+Evaluates to `std::sync::Mutex::new(arg)`.
+To share the `Mutex` between threads, use [`arc_mutex!`].
+
+```
+# use postfix_macros::{postfix_macros, mutex};
+# postfix_macros! {
+let state = vec![1].mutex!();
+state.lock().unwrap().push(2);
+assert_eq!(*state.lock().unwrap(), [1, 2]);
+# }
+```
+*/
+#[macro_export]
+macro_rules! mutex {
+	($v:expr) => {
+		std::sync::Mutex::new($v)
+	};
+}
+
+/**
+Puts the argument into a `RwLock`
+
+Meant to be used in a postfix context.
+Evaluates to `std::sync::RwLock::new(arg)`.
+
+```
+# use postfix_macros::{postfix_macros, rwlock};
+# postfix_macros! {
+let config = String::from("fast").rwlock!();
+*config.write().unwrap() = "slow".to_string();
+assert_eq!(*config.read().unwrap(), "slow");
+# }
+```
+*/
+#[macro_export]
+macro_rules! rwlock {
+	($v:expr) => {
+		std::sync::RwLock::new($v)
+	};
+}
+
+/**
+Puts the argument into an `Arc<Mutex<_>>`
+
+Meant to be used in a postfix context.
+Evaluates to `std::sync::Arc::new(std::sync::Mutex::new(arg))`,
+ready to be shared between threads.
+
+```
+# use postfix_macros::{postfix_macros, arc_mutex};
+# postfix_macros! {
+let hits = 0.arc_mutex!();
+let handles = (0..4).map(|_| {
+	let hits = hits.clone();
+	std::thread::spawn(move || *hits.lock().unwrap() += 1)
+}).collect::<Vec<_>>();
+for handle in handles {
+	handle.join().unwrap();
+}
+assert_eq!(*hits.lock().unwrap(), 4);
+# }
+```
+*/
+#[macro_export]
+macro_rules! arc_mutex {
+	($v:expr) => {
+		std::sync::Arc::new(std::sync::Mutex::new($v))
+	};
+}
+
+/**
+Pins the argument on the stack
+
+Meant to be used in a postfix context, e.g. to
+pin a future before polling it.
+Evaluates to [`std::pin::pin!`] applied to the argument,
+which is a `Pin<&mut T>`.
+Like with `std::pin::pin!`, the result can be
+stored in a `let` binding.
+To pin on the heap instead, use [`box_pin!`].
+
+Requires Rust 1.68 or later.
+
+```
+# use postfix_macros::{postfix_macros, pin};
+# use std::future::Future;
+# postfix_macros! {
+async fn answer() -> u32 {
+	42
+}
+let fut = answer().pin!();
+let _ :std::pin::Pin<&mut dyn Future<Output = u32>> = fut;
+# }
+```
+*/
+#[macro_export]
+macro_rules! pin {
+	($v:expr) => {
+		std::pin::pin!($v)
+	};
+}
+
+/**
+Pins the argument on the heap
+
+Meant to be used in a postfix context.
+Evaluates to `Box::pin(arg)`.
+
+```
+# use postfix_macros::{postfix_macros, box_pin};
+# use std::future::Future;
+# use std::pin::Pin;
+# postfix_macros! {
+async fn answer() -> u32 {
+	42
+}
+let futures :Vec<Pin<Box<dyn Future<Output = u32>>>> = vec![
+	answer().box_pin!(),
+	answer().box_pin!(),
+];
+assert_eq!(futures.len(), 2);
+# }
+```
+*/
+#[macro_export]
+macro_rules! box_pin {
+	($v:expr) => {
+		Box::pin($v)
+	};
+}
+
+/**
+Wraps the argument into `Cow::Borrowed`
+
+Meant to be used in a postfix context.
+Takes a reference, and evaluates to a
+[`Cow::Borrowed`](std::borrow::Cow::Borrowed) of it.
+
+```
+# use postfix_macros::{postfix_macros, cow_borrowed};
+# postfix_macros! {
+let name = "ferris".cow_borrowed!();
+assert_eq!(name.to_uppercase(), "FERRIS");
+# }
+```
+*/
+#[macro_export]
+macro_rules! cow_borrowed {
+	($v:expr) => {
+		std::borrow::Cow::Borrowed($v)
+	};
+}
+
+/**
+Wraps the argument into `Cow::Owned`
+
+Meant to be used in a postfix context.
+Evaluates to a [`Cow::Owned`](std::borrow::Cow::Owned)
+of the argument.
+
+As the borrowed type can't always be inferred from
+the owned one, it can be passed as optional parameter.
+
+```
+# use postfix_macros::{postfix_macros, cow_owned};
+# postfix_macros! {
+let name = "ferris".to_string().cow_owned!(str);
+assert_eq!(name, "ferris");
+
+let bytes = vec![1, 2].cow_owned!([u8]);
+assert_eq!(bytes.len(), 2);
+# }
+```
+*/
+#[macro_export]
+macro_rules! cow_owned {
+	($v:expr) => {
+		std::borrow::Cow::Owned($v)
+	};
+	($v:expr, $b:ty $(,)?) => {
+		std::borrow::Cow::<$b>::Owned($v)
+	};
+}
+
+/**
+Converts the argument into a `Cow`
+
+Meant to be used in a postfix context.
+Evaluates to `Cow::from(arg)`, so it turns
+references into [`Cow::Borrowed`](std::borrow::Cow::Borrowed)
+and owned values into [`Cow::Owned`](std::borrow::Cow::Owned).
+
+```
+# use postfix_macros::{postfix_macros, into_cow};
+# use std::borrow::Cow;
+# postfix_macros! {
+fn normalize(s :&str) -> Cow<str> {
+	if s.contains(' ') {
+		s.replace(' ', "_").into_cow!()
+	} else {
+		s.into_cow!()
+	}
+}
+assert!(matches!(normalize("a b"), Cow::Owned(_)));
+assert!(matches!(normalize("ab"), Cow::Borrowed("ab")));
+# }
+```
+*/
+#[macro_export]
+macro_rules! into_cow {
+	($v:expr) => {
+		std::borrow::Cow::from($v)
+	};
+}
+
+/**
+Wraps the argument into `Some`
+
+Meant to be used in a postfix context,
+at the end of a chain that needs to
+evaluate to an `Option`.
+Evaluates to `Some(arg)`.
+
+```
+# use postfix_macros::{postfix_macros, some};
+# postfix_macros! {
+fn first_word(s :&str) -> Option<String> {
+	if s.is_empty() {
+		return None;
+	}
+	s.split(' ').next().unwrap().to_uppercase().some!()
+}
+assert_eq!(first_word("hello world"), Some("HELLO".to_string()));
+# }
+```
+*/
+#[macro_export]
+macro_rules! some {
+	($v:expr) => {
+		Some($v)
+	};
+}
+
+/**
+Wraps the argument into `Ok`
+
+Meant to be used in a postfix context,
+at the end of a chain that needs to
+evaluate to a `Result`.
+Evaluates to `Ok(arg)`.
+
+```
+# use postfix_macros::{postfix_macros, ok_wrap};
+# postfix_macros! {
+fn parse_all(items :&[&str]) -> Result<Vec<u32>, std::num::ParseIntError> {
+	let mut res = Vec::new();
+	for item in items {
+		res.push(item.trim().parse()?);
+	}
+	res.ok_wrap!()
+}
+assert_eq!(parse_all(&["1", " 2"]), Ok(vec![1, 2]));
+# }
+```
+*/
+#[macro_export]
+macro_rules! ok_wrap {
+	($v:expr) => {
+		Ok($v)
+	};
+}
+
+/**
+Wraps the argument into `Err`
+
+Meant to be used in a postfix context,
+at the end of a chain that needs to
+evaluate to an error `Result`.
+Evaluates to `Err(arg)`.
+
+```
+# use postfix_macros::{postfix_macros, err_wrap};
+# postfix_macros! {
+fn check(len :usize) -> Result<(), String> {
+	if len > 3 {
+		return format!("too long: {}", len).to_uppercase().err_wrap!();
+	}
+	Ok(())
+}
+assert_eq!(check(5), Err("TOO LONG: 5".to_string()));
+# }
+```
+*/
+#[macro_export]
+macro_rules! err_wrap {
+	($v:expr) => {
+		Err($v)
+	};
+}
+
+/**
+Converts the argument into the given type
+
+Meant to be used in a postfix context.
+Evaluates to `Into::<T>::into(arg)`, so the target
+type is explicit at the invocation instead of
+having to be inferred.
+
+```
+# use postfix_macros::{postfix_macros, into_t};
+# postfix_macros! {
+let byte_count :u32 = 4096;
+let total = byte_count.into_t!(u64) * 1_000_000;
+assert_eq!(total, 4_096_000_000);
+
+let name = "ferris".into_t!(String).to_uppercase();
+assert_eq!(name, "FERRIS");
+# }
+```
+*/
+#[macro_export]
+macro_rules! into_t {
+	($v:expr, $t:ty $(,)?) => {
+		Into::<$t>::into($v)
+	};
+}
+
+/**
+Converts the argument into the given type, or evaluates to a fallback
+
+Meant to be used in a postfix context.
+Tries to convert the argument via `TryInto::<T>::try_into`.
+If the conversion fails, evaluates to the fallback instead,
+which is only evaluated in that case.
+Like with [`unwrap_or!`], the fallback can also
+contain control flow like `continue` or `return`.
+
+To return early if the conversion fails,
+use [`try_into_or_ret!`].
+
+```
+# use postfix_macros::{postfix_macros, try_into_or};
+# postfix_macros! {
+let big :u32 = 300;
+assert_eq!(big.try_into_or!(u8, u8::MAX), 255);
+assert_eq!(42u32.try_into_or!(u8, u8::MAX), 42);
+# }
+```
+*/
+#[macro_export]
+macro_rules! try_into_or {
+	($v:expr, $t:ty, $($w:tt)*) => {
+		match std::convert::TryInto::<$t>::try_into($v) {
+			Ok(v) => v,
+			Err(_) => { $($w)* },
+		}
+	};
+}
+
+/**
+Converts the argument into the given type, or returns
+
+Meant to be used in a postfix context.
+Tries to convert the argument via `TryInto::<T>::try_into`.
+If the conversion fails, returns from the enclosing function,
+with the optional second parameter as return value.
+
+```
+# use postfix_macros::{postfix_macros, try_into_or_ret};
+# postfix_macros! {
+fn to_percent(v :i64) -> Option<u8> {
+	let v = v.try_into_or_ret!(u8, None);
+	Some(v.min(100))
+}
+assert_eq!(to_percent(-1), None);
+assert_eq!(to_percent(150), Some(100));
+
+let mut seen = Vec::new();
+let mut record = |v :u64| {
+	seen.push(v.try_into_or_ret!(u16));
+};
+record(7);
+record(1 << 20);
+assert_eq!(seen, [7]);
+# }
+```
+*/
+#[macro_export]
+macro_rules! try_into_or_ret {
+	($v:expr, $t:ty $(,)?) => {
+		$crate::try_into_or!($v, $t, return)
+	};
+	($v:expr, $t:ty, $ret:expr $(,)?) => {
+		$crate::try_into_or!($v, $t, return $ret)
+	};
+}
+
+/**
+Parses the argument into the given type
+
+Meant to be used in a postfix context.
+Evaluates to `str::parse::<T>` applied to the argument,
+which can be anything implementing `AsRef<str>`.
+This avoids turbofish syntax in the middle of chains,
+while keeping the target type explicit.
+Evaluates to the `Result` of the parsing.
+
+```
+# use postfix_macros::{postfix_macros, parse_as};
+# postfix_macros! {
+let input = " 8080\n";
+let port = input.trim().parse_as!(u16).unwrap();
+assert_eq!(port, 8080);
+assert!("x".parse_as!(f64).is_err());
+# }
+```
+*/
+#[macro_export]
+macro_rules! parse_as {
+	($v:expr, $t:ty $(,)?) => {
+		str::parse::<$t>(AsRef::<str>::as_ref(&$v))
+	};
+}
+
+/**
+Parses the argument, falling back to a default value on failure
+
+Meant to be used in a postfix context.
+Parses the argument, which can be anything implementing
+`AsRef<str>`, via `str::parse` into the type of the default.
+If parsing fails, evaluates to the default,
+which is only evaluated in that case.
+
+If a message is given as second parameter, parse errors
+are printed to stderr together with the location
+of the invocation and the message.
+This requires the error type to implement `Display`.
+
+For parsing JSON, use `from_json_or!`.
+
+```
+# use postfix_macros::{postfix_macros, parse_or};
+# postfix_macros! {
+let port = std::env::var("PORT").unwrap_or_default().parse_or!(8080);
+assert!(port > 0);
+
+let workers = "many".parse_or!(4usize, "parsing WORKERS");
+assert_eq!(workers, 4);
+# }
+```
+*/
+#[macro_export]
+macro_rules! parse_or {
+	($v:expr, $default:expr) => {
+		match str::parse(AsRef::<str>::as_ref(&$v)) {
+			Ok(v) => v,
+			Err(_) => $default,
+		}
+	};
+	($v:expr, $default:expr, $msg:expr $(,)?) => {
+		match str::parse(AsRef::<str>::as_ref(&$v)) {
+			Ok(v) => v,
+			Err(e) => {
+				eprintln!("[{}:{}:{}] {}: {}", file!(), line!(), column!(), $msg, e);
+				$default
+			},
+		}
+	};
+}
+
+/**
+Converts the argument into the matching `NonZero*` type
+
+Meant to be used in a postfix context.
+Converts an integer into the matching `NonZero*` type,
+like `NonZeroUsize` for a `usize`, panicking if it is zero.
+Integer literals need a type suffix, as the type
+can't be inferred from the result.
+For an `Option` returning version, use [`nonzero_opt!`],
+and to return early instead, use [`nonzero_or_ret!`].
+
+```
+# use postfix_macros::{postfix_macros, nonzero};
+# use std::num::NonZeroUsize;
+# postfix_macros! {
+let items = vec!["a", "b"];
+let len :NonZeroUsize = items.len().nonzero!();
+assert_eq!(len.get(), 2);
+# }
+```
+*/
+#[macro_export]
+macro_rules! nonzero {
+	($v:expr) => {
+		match $crate::__private::IntoNonZero::into_nonzero($v) {
+			Some(v) => v,
+			None => panic!("[{}:{}:{}] {} was zero", file!(), line!(), column!(), stringify!($v)),
+		}
+	};
+}
+
+/**
+Converts the argument into the matching `NonZero*` type, if it isn't zero
+
+Meant to be used in a postfix context.
+Like [`nonzero!`], but evaluates to an `Option`
+that is `None` if the argument is zero.
+
+```
+# use postfix_macros::{postfix_macros, nonzero_opt};
+# postfix_macros! {
+assert_eq!(0u32.nonzero_opt!(), None);
+assert_eq!((-3i8).nonzero_opt!().map(|v| v.get()), Some(-3));
+# }
+```
+*/
+#[macro_export]
+macro_rules! nonzero_opt {
+	($v:expr) => {
+		$crate::__private::IntoNonZero::into_nonzero($v)
+	};
+}
+
+/**
+Converts the argument into the matching `NonZero*` type, or returns
+
+Meant to be used in a postfix context.
+Like [`nonzero!`], but if the argument is zero,
+returns from the enclosing function instead,
+with the optional parameter as return value.
+
+```
+# use postfix_macros::{postfix_macros, nonzero_or_ret};
+# postfix_macros! {
+fn average(sum :u64, count :u64) -> Option<u64> {
+	let count = count.nonzero_or_ret!(None);
+	Some(sum / count)
+}
+assert_eq!(average(10, 4), Some(2));
+assert_eq!(average(10, 0), None);
+# }
+```
+*/
+#[macro_export]
+macro_rules! nonzero_or_ret {
+	($v:expr) => {
+		match $crate::__private::IntoNonZero::into_nonzero($v) {
+			Some(v) => v,
+			None => return,
+		}
+	};
+	($v:expr, $ret:expr $(,)?) => {
+		match $crate::__private::IntoNonZero::into_nonzero($v) {
+			Some(v) => v,
+			None => return $ret,
+		}
+	};
+}
+
+/**
+Converts the integer argument into the given integer type, saturating
+
+Meant to be used in a postfix context.
+Converts the argument into the given integer type,
+clamping it to the bounds of that type if it doesn't fit.
+Integer literals need a type suffix.
+
+Unlike `as` casts, the truncation isn't silent.
+For wrapping behaviour, use [`wrapping_as!`],
+and for checked conversions, use [`checked_as!`].
+
+```
+# use postfix_macros::{postfix_macros, saturating_as};
+# postfix_macros! {
+let offset :i64 = 70_000;
+assert_eq!(offset.saturating_as!(u16), u16::MAX);
+assert_eq!((-5i64).saturating_as!(u16), 0);
+assert_eq!(300u32.saturating_as!(i16), 300);
+# }
+```
+*/
+#[macro_export]
+macro_rules! saturating_as {
+	($v:expr, $t:ty $(,)?) => {
+		$crate::__private::IntCast::<$t>::saturating_cast($v)
+	};
+}
+
+/**
+Converts the integer argument into the given integer type, wrapping
+
+Meant to be used in a postfix context.
+Converts the argument into the given integer type,
+wrapping around at the bounds of the type,
+which is the same behaviour as an `as` cast.
+Unlike `as`, only integer types are accepted,
+and the wrapping is made explicit.
+Integer literals need a type suffix.
+
+```
+# use postfix_macros::{postfix_macros, wrapping_as};
+# postfix_macros! {
+assert_eq!(300u32.wrapping_as!(u8), 44);
+assert_eq!((-1i32).wrapping_as!(u32), u32::MAX);
+# }
+```
+*/
+#[macro_export]
+macro_rules! wrapping_as {
+	($v:expr, $t:ty $(,)?) => {
+		$crate::__private::IntCast::<$t>::wrapping_cast($v)
+	};
+}
+
+/**
+Converts the argument into the given type, if it fits
+
+Meant to be used in a postfix context.
+Converts the argument into the given type via `TryInto`,
+evaluating to `None` if it doesn't fit.
+
+```
+# use postfix_macros::{postfix_macros, checked_as};
+# postfix_macros! {
+let len :usize = 42;
+assert_eq!(len.checked_as!(u8), Some(42));
+assert_eq!((-1i32).checked_as!(usize), None);
+# }
+```
+*/
+#[macro_export]
+macro_rules! checked_as {
+	($v:expr, $t:ty $(,)?) => {
+		std::convert::TryInto::<$t>::try_into($v).ok()
+	};
+}
+
+/**
+Interprets the argument as UTF-8, or evaluates to a fallback
+
+Meant to be used in a postfix context.
+Runs [`std::str::from_utf8`] on the argument, which can be
+anything implementing `AsRef<[u8]>`, and evaluates to the `&str`.
+If the bytes are not valid UTF-8, evaluates to the fallback
+instead, which is only evaluated in that case.
+To replace invalid sequences instead, use [`utf8_lossy!`].
+
+```
+# use postfix_macros::{postfix_macros, utf8_or};
+# postfix_macros! {
+let buf = b"hello".to_vec();
+assert_eq!(buf.utf8_or!("<invalid>"), "hello");
+
+let garbage = [0xff, 0xfe];
+assert_eq!(garbage.utf8_or!("<invalid>").len(), 9);
+# }
+```
+*/
+#[macro_export]
+macro_rules! utf8_or {
+	($v:expr, $($w:tt)*) => {
+		match std::str::from_utf8(AsRef::<[u8]>::as_ref(&$v)) {
+			Ok(s) => s,
+			Err(_) => { $($w)* },
+		}
+	};
+}
+
+/**
+Interprets the argument as UTF-8, replacing invalid sequences
+
+Meant to be used in a postfix context.
+Runs [`String::from_utf8_lossy`] on the argument, which can be
+anything implementing `AsRef<[u8]>`, and evaluates to the
+resulting `Cow<str>`, where invalid sequences are replaced
+with `U+FFFD REPLACEMENT CHARACTER`.
+
+```
+# use postfix_macros::{postfix_macros, utf8_lossy};
+# postfix_macros! {
+let buf = vec![b'o', b'k', 0xff];
+assert_eq!(buf.utf8_lossy!(), "ok\u{FFFD}");
+# }
+```
+*/
+#[macro_export]
+macro_rules! utf8_lossy {
+	($v:expr) => {
+		String::from_utf8_lossy(AsRef::<[u8]>::as_ref(&$v))
+	};
+}
+
+/**
+Clamps the argument into the given range
+
+Meant to be used in a postfix context.
+Takes an inclusive range like `lo..=hi`, or a half-open
+one like `lo..` or `..=hi`, and evaluates to the argument,
+clamped into that range.
+Works with any `PartialOrd` type, including floats.
+
+```
+# use postfix_macros::{postfix_macros, clamp_to};
+# postfix_macros! {
+let volume = 1.3f32;
+assert_eq!(volume.clamp_to!(0.0..=1.0), 1.0);
+assert_eq!((-7).clamp_to!(0..), 0);
+assert_eq!(42u8.clamp_to!(..=100), 42);
+# }
+```
+*/
+#[macro_export]
+macro_rules! clamp_to {
+	($v:expr, $range:expr $(,)?) => {
+		$crate::__private::ClampRange::clamp_value(&$range, $v)
+	};
+}
+
+/**
+Checks whether the argument is inside the given range
+
+Meant to be used in a postfix context.
+Takes any range expression, like `lo..hi`, `lo..=hi`
+or `lo..`, and evaluates to `true` if the argument
+is contained in the range.
+
+```
+# use postfix_macros::{postfix_macros, is_between, then_some};
+# postfix_macros! {
+let code = 204;
+assert!(code.is_between!(200..300));
+assert!(!code.is_between!(300..));
+
+let label = code.is_between!(200..=299).then_some!{ "success" };
+assert_eq!(label, Some("success"));
+# }
+```
+*/
+#[macro_export]
+macro_rules! is_between {
+	($v:expr, $range:expr $(,)?) => {
+		std::ops::RangeBounds::contains(&$range, &$v)
+	};
+}
+
+/**
+Asserts the type of the argument at compile time
+
+Meant to be used in a postfix context, to pin down
+type inference in the middle of a chain.
+Fails to compile if the argument's type isn't
+the given type, or can't be coerced to it.
+Otherwise, evaluates to the argument, with no runtime cost.
+
+```
+# use postfix_macros::{postfix_macros, assert_type};
+# postfix_macros! {
+let words = vec!["a", "b"];
+let upper = words.iter()
+	.map(|w| w.to_uppercase())
+	.collect()
+	.assert_type!(Vec<String>);
+assert_eq!(upper, ["A", "B"]);
+# }
+```
+
+Mismatching types fail to compile:
+
+```compile_fail
+# use postfix_macros::{postfix_macros, assert_type};
+# postfix_macros! {
+let v = 42u8.assert_type!(String);
+# }
+```
+*/
+#[macro_export]
+macro_rules! assert_type {
+	($v:expr, $t:ty $(,)?) => {{
+		let v :$t = $v;
+		v
+	}};
+}
+
+/**
+Asserts that the argument's type satisfies trait bounds
+
+Meant to be used in a postfix context, to catch
+e.g. accidental `!Send` captures right where
+a value is created.
+Takes a list of bounds in the same syntax as used for
+generic parameters, and fails to compile if the
+argument's type doesn't satisfy them.
+Otherwise, evaluates to the argument, with no runtime cost.
+
+```
+# use postfix_macros::{postfix_macros, assert_impl};
+# postfix_macros! {
+let data = std::sync::Arc::new(vec![1, 2]);
+let handle = std::thread::spawn(move || data.len()).assert_impl!(Send + Sync + 'static);
+assert_eq!(handle.join().unwrap(), 2);
+# }
+```
+
+Types not satisfying the bounds fail to compile:
+
+```compile_fail
+# use postfix_macros::{postfix_macros, assert_impl};
+# postfix_macros! {
+let rc = std::rc::Rc::new(1).assert_impl!(Send);
+# }
+```
+*/
+#[macro_export]
+macro_rules! assert_impl {
+	($v:expr, $($bounds:tt)+) => {
+		match $v {
+			v => {
+				fn assert_impl<T :?Sized + $($bounds)+>(_ :&T) {}
+				assert_impl(&v);
+				v
+			},
+		}
+	};
+}
+
+/**
+Asserts the size of the argument's type at compile time
+
+Meant to be used in a postfix context, e.g. for
+FFI and wire format structs.
+Fails to compile if the size of the argument's type
+in bytes isn't the given value.
+Otherwise, evaluates to the argument, with no runtime cost.
+
+As the check happens when the code is monomorphized,
+the error shows up in `cargo build`, but not necessarily
+in `cargo check`.
+For the alignment, use [`static_assert_align!`].
+
+Requires Rust 1.57 or later.
+
+```
+# use postfix_macros::{postfix_macros, static_assert_size};
+# postfix_macros! {
+#[repr(C)]
+struct Header {
+	magic :u32,
+	len :u32,
+	checksum :u64,
+}
+let header = Header { magic : 0xcafe, len : 0, checksum : 0 };
+let header = header.static_assert_size!(16);
+assert_eq!(header.magic, 0xcafe);
+# }
+```
+
+```compile_fail
+# use postfix_macros::{postfix_macros, static_assert_size};
+# postfix_macros! {
+let v = 1u32.static_assert_size!(8);
+# }
+```
+*/
+#[macro_export]
+macro_rules! static_assert_size {
+	($v:expr, $size:expr $(,)?) => {
+		match $v {
+			v => {
+				struct AssertSize<T>(std::marker::PhantomData<T>);
+				impl<T> AssertSize<T> {
+					const OK :() = assert!(std::mem::size_of::<T>() == $size,
+						concat!("type size is not ", stringify!($size), " bytes"));
+				}
+				fn assert_size<T>(_ :&T) {
+					let () = AssertSize::<T>::OK;
+				}
+				assert_size(&v);
+				v
+			},
+		}
+	};
+}
+
+/**
+Asserts the alignment of the argument's type at compile time
+
+Meant to be used in a postfix context.
+Like [`static_assert_size!`], but checks
+the alignment of the type in bytes.
+
+Requires Rust 1.57 or later.
+
+```
+# use postfix_macros::{postfix_macros, static_assert_align};
+# postfix_macros! {
+#[repr(C, align(8))]
+struct Aligned([u8; 3]);
+let v = Aligned([1, 2, 3]).static_assert_align!(8);
+assert_eq!(v.0[2], 3);
+# }
+```
+
+```compile_fail
+# use postfix_macros::{postfix_macros, static_assert_align};
+# postfix_macros! {
+let v = 1u8.static_assert_align!(4);
+# }
+```
+*/
+#[macro_export]
+macro_rules! static_assert_align {
+	($v:expr, $align:expr $(,)?) => {
+		match $v {
+			v => {
+				struct AssertAlign<T>(std::marker::PhantomData<T>);
+				impl<T> AssertAlign<T> {
+					const OK :() = assert!(std::mem::align_of::<T>() == $align,
+						concat!("type alignment is not ", stringify!($align), " bytes"));
+				}
+				fn assert_align<T>(_ :&T) {
+					let () = AssertAlign::<T>::OK;
+				}
+				assert_align(&v);
+				v
+			},
+		}
+	};
+}
+
+/**
+Asserts a condition on a constant at compile time
+
+Meant to be used in a postfix context, so that constants
+can carry their invariants at the definition site.
+Takes either a comparison like `> 0`, or a call
+of a `const fn` method like `is_power_of_two()`,
+optionally followed by more operations like `len() < 8`,
+and checks it for the argument at compile time.
+Evaluates to the argument.
+
+The argument needs to be evaluable in a const context,
+like a constant or a literal.
+
+Requires Rust 1.57 or later.
+
+```
+# use postfix_macros::{postfix_macros, const_assert};
+# postfix_macros! {
+const LIMIT :usize = 64;
+let buf = vec![0u8; LIMIT.const_assert!(> 0).const_assert!(is_power_of_two())];
+assert_eq!(buf.len(), 64);
+# }
+```
+
+```compile_fail
+# use postfix_macros::{postfix_macros, const_assert};
+# postfix_macros! {
+const LIMIT :u32 = 0;
+let limit = LIMIT.const_assert!(!= 0);
+# }
+```
+*/
+#[macro_export]
+macro_rules! const_assert {
+	($v:expr, $method:ident $($rest:tt)*) => {{
+		const _ :() = assert!($v.$method $($rest)*,
+			concat!("const assertion failed: ", stringify!($v.$method $($rest)*)));
+		$v
+	}};
+	($v:expr, $($cond:tt)+) => {{
+		const _ :() = assert!($v $($cond)+,
+			concat!("const assertion failed: ", stringify!($v $($cond)+)));
+		$v
+	}};
+}
+
+/**
+Collects the iterator into a `Vec`
+
+Meant to be used in a postfix context.
+Evaluates to `Iterator::collect::<Vec<_>>(arg)`,
+avoiding turbofish syntax at the end of chains.
+
+```
+# use postfix_macros::{postfix_macros, collect_vec};
+# postfix_macros! {
+let words = vec![" a", "b "];
+let clean = words.iter().map(|w| w.trim()).collect_vec!();
+assert_eq!(clean, ["a", "b"]);
+# }
+```
+*/
+#[macro_export]
+macro_rules! collect_vec {
+	($v:expr) => {
+		Iterator::collect::<Vec<_>>($v)
+	};
+}
+
+/**
+Collects the iterator into a `HashSet`
+
+Meant to be used in a postfix context.
+Evaluates to `Iterator::collect::<HashSet<_>>(arg)`.
+
+```
+# use postfix_macros::{postfix_macros, collect_set};
+# postfix_macros! {
+let tags = vec!["a", "b", "a"].into_iter().collect_set!();
+assert_eq!(tags.len(), 2);
+# }
+```
+*/
+#[macro_export]
+macro_rules! collect_set {
+	($v:expr) => {
+		Iterator::collect::<std::collections::HashSet<_>>($v)
+	};
+}
+
+/**
+Collects the iterator of pairs into a `HashMap`
+
+Meant to be used in a postfix context.
+Evaluates to `Iterator::collect::<HashMap<_, _>>(arg)`,
+for iterators of `(key, value)` tuples.
+
+```
+# use postfix_macros::{postfix_macros, collect_map};
+# postfix_macros! {
+let lens = vec!["one", "three"].into_iter().map(|w| (w, w.len())).collect_map!();
+assert_eq!(lens["three"], 5);
+# }
+```
+*/
+#[macro_export]
+macro_rules! collect_map {
+	($v:expr) => {
+		Iterator::collect::<std::collections::HashMap<_, _>>($v)
+	};
+}
+
+/**
+Collects the iterator into a `String`
+
+Meant to be used in a postfix context.
+Evaluates to `Iterator::collect::<String>(arg)`,
+for iterators of `char`s or strings.
+
+```
+# use postfix_macros::{postfix_macros, collect_string};
+# postfix_macros! {
+let shout = "hello".chars().map(|c| c.to_ascii_uppercase()).collect_string!();
+assert_eq!(shout, "HELLO");
+# }
+```
+*/
+#[macro_export]
+macro_rules! collect_string {
+	($v:expr) => {
+		Iterator::collect::<String>($v)
+	};
+}
+
+/**
+Filters an iterator by a pattern
+
+Meant to be used in a postfix context.
+Evaluates to the iterator, filtered to the elements
+matching the given pattern, with an optional `if` guard.
+Bindings of the pattern are references to the element.
+
+```
+# use postfix_macros::{postfix_macros, filter_matches};
+# postfix_macros! {
+enum Event {
+	Key(char),
+	Click { x :u32, y :u32 },
+}
+let events = vec![Event::Key('a'), Event::Click { x : 1, y : 2 }, Event::Key('b')];
+assert_eq!(events.iter().filter_matches!(Event::Key(_)).count(), 2);
+
+let far = events.iter()
+	.filter_matches!(Event::Click { x, .. } | Event::Click { y : x, .. } if *x > 1)
+	.count();
+assert_eq!(far, 1);
+# }
+```
+*/
+#[macro_export]
+macro_rules! filter_matches {
+	($v:expr, $(|)? $($pat:pat)|+ $(if $guard:expr)? $(,)?) => {
+		Iterator::filter($v, |v| matches!(v, $($pat)|+ $(if $guard)?))
+	};
+}
+
+/**
+Finds the first element of an iterator matching a pattern
+
+Meant to be used in a postfix context.
+Evaluates to an `Option` of the first element matching
+the given pattern, with an optional `if` guard.
+Bindings of the pattern are references to the element.
+To obtain the bindings instead, use [`find_map_matches!`].
+
+```
+# use postfix_macros::{postfix_macros, find_matches};
+# postfix_macros! {
+#[derive(Debug, PartialEq)]
+enum Frame {
+	Ping,
+	Data { len :usize, payload :&'static str },
+}
+let frames = vec![Frame::Ping, Frame::Data { len : 0, payload : "" },
+	Frame::Data { len : 2, payload : "hi" }];
+let first = frames.iter().find_matches!(Frame::Data { len, .. } if *len > 0);
+assert_eq!(first, Some(&Frame::Data { len : 2, payload : "hi" }));
+# }
+```
+*/
+#[macro_export]
+macro_rules! find_matches {
+	($v:expr, $(|)? $($pat:pat)|+ $(if $guard:expr)? $(,)?) => {
+		match $v {
+			mut it => Iterator::find(&mut it, |v| matches!(v, $($pat)|+ $(if $guard)?)),
+		}
+	};
+}
+
+/**
+Finds the first element of an iterator matching a pattern, mapping it
+
+Meant to be used in a postfix context.
+Takes a pattern with an optional `if` guard, followed by
+`=>` and an expression that can use the pattern's bindings.
+Evaluates to `Some` of the expression for the
+first matching element, or `None`.
+
+```
+# use postfix_macros::{postfix_macros, find_map_matches};
+# postfix_macros! {
+enum Frame {
+	Ping,
+	Data { len :usize, payload :String },
+}
+let frames = vec![Frame::Ping, Frame::Data { len : 2, payload : "hi".to_string() }];
+let payload = frames.into_iter()
+	.find_map_matches!(Frame::Data { len, payload } if len > 0 => payload);
+assert_eq!(payload.as_deref(), Some("hi"));
+# }
+```
+*/
+#[macro_export]
+macro_rules! find_map_matches {
+	($v:expr, $(|)? $($pat:pat)|+ $(if $guard:expr)? => $res:expr $(,)?) => {
+		match $v {
+			mut it => Iterator::find_map(&mut it, |v| match v {
+				$($pat)|+ $(if $guard)? => Some($res),
+				_ => None,
+			}),
+		}
+	};
+}
+
+/**
+Counts the elements of an iterator matching a pattern
+
+Meant to be used in a postfix context.
+Evaluates to the number of elements matching
+the given pattern, with an optional `if` guard.
+Bindings of the pattern are references to the element.
+
+```
+# use postfix_macros::{postfix_macros, count_matches};
+# postfix_macros! {
+enum Token {
+	Comma,
+	Ident(&'static str),
+}
+let tokens = vec![Token::Ident("a"), Token::Comma, Token::Ident("bc"), Token::Comma];
+assert_eq!(tokens.iter().count_matches!(Token::Comma), 2);
+assert_eq!(tokens.iter().count_matches!(Token::Ident(s) if s.len() > 1), 1);
+# }
+```
+*/
+#[macro_export]
+macro_rules! count_matches {
+	($v:expr, $(|)? $($pat:pat)|+ $(if $guard:expr)? $(,)?) => {
+		Iterator::count(Iterator::filter($v, |v| matches!(v, $($pat)|+ $(if $guard)?)))
+	};
+}
+
+/**
+Finds the index of the first element of an iterator matching a pattern
+
+Meant to be used in a postfix context.
+Evaluates to an `Option` of the index of the first element
+matching the given pattern, with an optional `if` guard.
+Bindings of the pattern are references to the element.
+
+```
+# use postfix_macros::{postfix_macros, position_matches};
+# postfix_macros! {
+enum Line {
+	Text(&'static str),
+	Header(u8),
+}
+let lines = vec![Line::Text("intro"), Line::Header(1), Line::Header(2)];
+assert_eq!(lines.iter().position_matches!(Line::Header(_)), Some(1));
+assert_eq!(lines.iter().position_matches!(Line::Header(l) if *l > 2), None);
+# }
+```
+*/
+#[macro_export]
+macro_rules! position_matches {
+	($v:expr, $(|)? $($pat:pat)|+ $(if $guard:expr)? $(,)?) => {
+		match $v {
+			mut it => Iterator::position(&mut it, |v| matches!(&v, $($pat)|+ $(if $guard)?)),
+		}
+	};
+}
+
+/**
+Checks whether any element of an iterator matches a pattern
+
+Meant to be used in a postfix context.
+Evaluates to `true` if any element matches the given
+pattern, with an optional `if` guard.
+Bindings of the pattern are references to the element.
+See [`all_matches!`] for the counterpart.
+
+```
+# use postfix_macros::{postfix_macros, any_matches, then_some};
+# postfix_macros! {
+enum State {
+	Running,
+	Failed(&'static str),
+}
+let states = vec![State::Running, State::Failed("disk full")];
+assert!(states.iter().any_matches!(State::Failed(_)));
+let msg = states.iter().any_matches!(State::Failed(e) if e.contains("disk")).then_some!{ "check disk" };
+assert_eq!(msg, Some("check disk"));
+# }
+```
+*/
+#[macro_export]
+macro_rules! any_matches {
+	($v:expr, $(|)? $($pat:pat)|+ $(if $guard:expr)? $(,)?) => {
+		match $v {
+			mut it => Iterator::any(&mut it, |v| matches!(&v, $($pat)|+ $(if $guard)?)),
+		}
+	};
+}
+
+/**
+Checks whether all elements of an iterator match a pattern
+
+Meant to be used in a postfix context.
+Evaluates to `true` if all elements match the given
+pattern, with an optional `if` guard, or if
+the iterator is empty.
+Bindings of the pattern are references to the element.
+See [`any_matches!`] for the counterpart.
+
+```
+# use postfix_macros::{postfix_macros, all_matches};
+# postfix_macros! {
+let results :Vec<Result<u8, ()>> = vec![Ok(1), Ok(2)];
+assert!(results.iter().all_matches!(Ok(_)));
+assert!(!results.iter().all_matches!(Ok(v) if *v > 1));
+# }
+```
+*/
+#[macro_export]
+macro_rules! all_matches {
+	($v:expr, $(|)? $($pat:pat)|+ $(if $guard:expr)? $(,)?) => {
+		match $v {
+			mut it => Iterator::all(&mut it, |v| matches!(&v, $($pat)|+ $(if $guard)?)),
+		}
+	};
+}
+
+/**
+Pushes the argument into the given `Vec`
+
+Meant to be used in a postfix context,
+at the end of a chain that computes a value
+that needs to be stored somewhere.
+Evaluates to `()`.
+To keep using the argument, use [`push_clone_to!`].
+
+```
+# use postfix_macros::{postfix_macros, push_to};
+# postfix_macros! {
+let mut results = Vec::new();
+for i in 0..3 {
+	(i * 10).to_string().push_to!(results);
+}
+assert_eq!(results, ["0", "10", "20"]);
+# }
+```
+*/
+#[macro_export]
+macro_rules! push_to {
+	($v:expr, $target:expr $(,)?) => {
+		$target.push($v)
+	};
+}
+
+/**
+Pushes a clone of the argument into the given `Vec`
+
+Meant to be used in a postfix context.
+Like [`push_to!`], but pushes a clone of the argument,
+and evaluates to the argument.
+
+```
+# use postfix_macros::{postfix_macros, push_clone_to};
+# postfix_macros! {
+let mut history = Vec::new();
+let state = String::from("idle").push_clone_to!(history).to_uppercase();
+assert_eq!(state, "IDLE");
+assert_eq!(history, ["idle"]);
+# }
+```
+*/
+#[macro_export]
+macro_rules! push_clone_to {
+	($v:expr, $target:expr $(,)?) => {
+		match $v {
+			v => {
+				$target.push(Clone::clone(&v));
+				v
+			},
+		}
+	};
+}
+
+/**
+Inserts the argument into the given map or set
+
+Meant to be used in a postfix context.
+If a key is given, inserts the argument as value
+for the key into the given map, like a `HashMap`,
+evaluating to the previous value.
+Otherwise, inserts the argument into the given set,
+like a `HashSet`, evaluating to whether it was newly inserted.
+
+```
+# use postfix_macros::{postfix_macros, insert_into};
+# use std::collections::{BTreeMap, HashSet};
+# postfix_macros! {
+let mut sizes = BTreeMap::new();
+let mut seen = HashSet::new();
+for name in vec!["a.txt", "bb.txt", "a.txt"] {
+	if name.insert_into!(seen) {
+		name.len().insert_into!(sizes, name);
+	}
+}
+assert_eq!(sizes.len(), 2);
+assert_eq!("x".len().insert_into!(sizes, "a.txt"), Some(5));
+# }
+```
+*/
+#[macro_export]
+macro_rules! insert_into {
+	($v:expr, $target:expr $(,)?) => {
+		$target.insert($v)
+	};
+	($v:expr, $target:expr, $key:expr $(,)?) => {
+		$target.insert($key, $v)
+	};
+}
+
+/**
+Joins an iterator of strings with a separator
+
+Meant to be used in a postfix context.
+Takes a separator, and evaluates to a `String` of the
+elements of the iterator, which can be anything
+implementing `AsRef<str>`, joined by the separator.
+
+```
+# use postfix_macros::{postfix_macros, join_str};
+# postfix_macros! {
+let names = vec![String::from("ann"), String::from("bob")];
+assert_eq!(names.iter().join_str!(", "), "ann, bob");
+assert_eq!("a b c".split(' ').rev().join_str!("-"), "c-b-a");
+# }
+```
+*/
+#[macro_export]
+macro_rules! join_str {
+	($v:expr, $sep:expr $(,)?) => {
+		$crate::__private::join_str($v, $sep)
+	};
+}
+
+/**
+Sums up the elements of an iterator into the given type
+
+Meant to be used in a postfix context.
+Evaluates to `Iterator::sum::<T>(arg)`, avoiding
+turbofish syntax in the middle of chains.
+
+```
+# use postfix_macros::{postfix_macros, sum_as};
+# postfix_macros! {
+let sizes :Vec<u64> = vec![512, 1024];
+let total = sizes.iter().sum_as!(u64).to_string();
+assert_eq!(total, "1536");
+# }
+```
+*/
+#[macro_export]
+macro_rules! sum_as {
+	($v:expr, $t:ty $(,)?) => {
+		Iterator::sum::<$t>($v)
+	};
+}
+
+/**
+Multiplies the elements of an iterator into the given type
+
+Meant to be used in a postfix context.
+Evaluates to `Iterator::product::<T>(arg)`, avoiding
+turbofish syntax in the middle of chains.
+
+```
+# use postfix_macros::{postfix_macros, product_as};
+# postfix_macros! {
+let factorial = (1..=5).product_as!(u32).to_string();
+assert_eq!(factorial, "120");
+# }
+```
+*/
+#[macro_export]
+macro_rules! product_as {
+	($v:expr, $t:ty $(,)?) => {
+		Iterator::product::<$t>($v)
+	};
+}
+
+/**
+Replaces the argument with a new value
+
+Meant to be used in a postfix context.
+Evaluates to `std::mem::replace(&mut arg, new)`,
+so the argument needs to be a place expression,
+like a variable or a field.
+The place is borrowed, not moved out of.
+
+```
+# use postfix_macros::{postfix_macros, replace_with};
+# postfix_macros! {
+struct Conn {
+	state :String,
+}
+let mut conn = Conn { state : "idle".to_string() };
+let old = conn.state.replace_with!("busy".to_string());
+assert_eq!(old, "idle");
+assert_eq!(conn.state, "busy");
+# }
+```
+*/
+#[macro_export]
+macro_rules! replace_with {
+	({ $($place:tt)* }, $new:expr $(,)?) => {
+		std::mem::replace(&mut $($place)*, $new)
+	};
+	($place:expr, $new:expr $(,)?) => {
+		std::mem::replace(&mut $place, $new)
+	};
+}
+
+/**
+Swaps the argument with another place
+
+Meant to be used in a postfix context.
+Evaluates to `std::mem::swap(&mut arg, &mut other)`,
+so both need to be place expressions,
+like variables or fields.
+
+```
+# use postfix_macros::{postfix_macros, swap_with};
+# postfix_macros! {
+let mut pair = (vec![1], vec![2, 3]);
+let mut spare = Vec::new();
+pair.0.swap_with!(spare);
+pair.1.swap_with!(pair.0);
+assert_eq!(pair, (vec![2, 3], vec![]));
+assert_eq!(spare, [1]);
+# }
+```
+*/
+#[macro_export]
+macro_rules! swap_with {
+	({ $($place:tt)* }, $other:expr $(,)?) => {
+		std::mem::swap(&mut $($place)*, &mut $other)
+	};
+	($place:expr, $other:expr $(,)?) => {
+		std::mem::swap(&mut $place, &mut $other)
+	};
+}
+
+/**
+Takes the argument, leaving its default value in its place
+
+Meant to be used in a postfix context.
+Evaluates to `std::mem::take(&mut arg)`,
+so the argument needs to be a place expression,
+like a variable or a field.
+
+```
+# use postfix_macros::{postfix_macros, take_mem};
+# postfix_macros! {
+struct Batch {
+	items :Vec<u32>,
+}
+let mut batch = Batch { items : vec![1, 2] };
+let items = batch.items.take_mem!();
+assert_eq!(items, [1, 2]);
+assert!(batch.items.is_empty());
+# }
+```
+*/
+#[macro_export]
+macro_rules! take_mem {
+	({ $($place:tt)* }) => {
+		std::mem::take(&mut $($place)*)
+	};
+	($place:expr) => {
+		std::mem::take(&mut $place)
+	};
+}
+
+/**
+Repeats the argument a given number of times
+
+Meant to be used in a postfix context.
+Takes a count `n`, and evaluates to a `Vec` of `n` clones
+of the argument, like `vec![arg; n]`.
+
+Pass `iter` after the count to obtain an iterator
+yielding `n` clones instead.
+Put the count into brackets, like `[4]`, to obtain
+an array instead, which requires the count to be a constant.
+The array form requires Rust 1.63 or later.
+
+```
+# use postfix_macros::{postfix_macros, repeat_n};
+# postfix_macros! {
+let row = "-".to_string().repeat_n!(3);
+assert_eq!(row, ["-", "-", "-"]);
+
+let line = '='.repeat_n!(4, iter).collect::<String>();
+assert_eq!(line, "====");
+
+let grid :[Vec<u8>; 2] = vec![0u8; 2].repeat_n!([2]);
+assert_eq!(grid, [[0, 0], [0, 0]]);
+# }
+```
+*/
+#[macro_export]
+macro_rules! repeat_n {
+	($v:expr, [$n:expr] $(,)?) => {
+		match $v {
+			v => std::array::from_fn::<_, $n, _>(|_| Clone::clone(&v)),
+		}
+	};
+	($v:expr, $n:expr, iter $(,)?) => {
+		Iterator::take(std::iter::repeat($v), $n)
+	};
+	($v:expr, $n:expr $(,)?) => {
+		vec![$v; $n]
+	};
+}
+
+/**
+Gets the first or last element of a slice, or evaluates the fallback
+
+Implementation detail of [`first_or!`], [`last_or!`],
+[`first_or_ret!`], and [`last_or_ret!`].
+The receiver is borrowed, not moved.
+*/
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __slice_end_or {
+	($method:ident, { $($v:tt)* }, $($w:tt)*) => {
+		match ($($v)*).$method() {
+			Some(v) => v,
+			None => { $($w)* },
+		}
+	};
+	($method:ident, $v:expr, $($w:tt)*) => {
+		match $v.$method() {
+			Some(v) => v,
+			None => { $($w)* },
+		}
+	};
+}
+
+/**
+Gets a reference to the first element, or evaluates to a fallback
+
+Meant to be used in a postfix context.
+Evaluates to a reference to the first element of the
+argument, which can be a slice or anything that
+dereferences to one, like a `Vec`.
+The argument is borrowed, not moved.
+
+If there is no element, evaluates to the fallback instead,
+which is only evaluated in that case.
+Like with [`unwrap_or!`], the fallback can also
+contain control flow like `continue` or `return`.
+To return early, use [`first_or_ret!`].
+
+```
+# use postfix_macros::{postfix_macros, first_or};
+# postfix_macros! {
+struct Cli {
+	args :Vec<&'static str>,
+}
+let cli = Cli { args : vec![] };
+let cmd = cli.args.first_or!(&"help");
+assert_eq!(*cmd, "help");
+assert!(cli.args.is_empty());
+# }
+```
+*/
+#[macro_export]
+macro_rules! first_or {
+	($v:tt, $($w:tt)*) => {
+		$crate::__slice_end_or!(first, $v, $($w)*)
+	};
+}
+
+/**
+Gets a reference to the last element, or evaluates to a fallback
+
+Meant to be used in a postfix context.
+Like [`first_or!`], but for the last element.
+To return early, use [`last_or_ret!`].
+
+```
+# use postfix_macros::{postfix_macros, last_or};
+# postfix_macros! {
+let path = "a/b/c".split('/').collect::<Vec<_>>();
+assert_eq!(*path.last_or!(&""), "c");
+# }
+```
+*/
+#[macro_export]
+macro_rules! last_or {
+	($v:tt, $($w:tt)*) => {
+		$crate::__slice_end_or!(last, $v, $($w)*)
+	};
+}
+
+/**
+Gets a reference to the first element, or returns
+
+Meant to be used in a postfix context.
+Like [`first_or!`], but if there is no element, returns
+from the enclosing function, with the optional
+parameter as return value.
+
+```
+# use postfix_macros::{postfix_macros, first_or_ret};
+# postfix_macros! {
+fn head_len(lines :&[String]) -> Option<usize> {
+	let head = lines.first_or_ret!(None);
+	Some(head.len())
+}
+assert_eq!(head_len(&["abc".to_string()]), Some(3));
+assert_eq!(head_len(&[]), None);
+# }
+```
+*/
+#[macro_export]
+macro_rules! first_or_ret {
+	($v:tt) => {
+		$crate::__slice_end_or!(first, $v, return)
+	};
+	($v:tt, $ret:expr $(,)?) => {
+		$crate::__slice_end_or!(first, $v, return $ret)
+	};
+}
+
+/**
+Gets a reference to the last element, or returns
+
+Meant to be used in a postfix context.
+Like [`first_or_ret!`], but for the last element.
+
+```
+# use postfix_macros::{postfix_macros, last_or_ret};
+# postfix_macros! {
+fn print_last(vals :&[u8]) {
+	let last = vals.last_or_ret!();
+	println!("last: {}", last);
+}
+print_last(&[]);
+print_last(&[1, 2]);
+# }
+```
+*/
+#[macro_export]
+macro_rules! last_or_ret {
+	($v:tt) => {
+		$crate::__slice_end_or!(last, $v, return)
+	};
+	($v:tt, $ret:expr $(,)?) => {
+		$crate::__slice_end_or!(last, $v, return $ret)
+	};
+}
+
+/**
+Finds the minimum and maximum of an iterator in a single pass
+
+Meant to be used in a postfix context.
+Evaluates to `Some((min, max))` of the elements of the iterator,
+or `None` if it is empty.
+Like with [`Iterator::min`] and [`Iterator::max`], the first
+minimum and the last maximum are returned.
+Works with floats too, where elements that can't be
+compared, like `NaN`, are skipped unless they come first.
+To compare by a key, use [`min_max_by_key!`].
+
+```
+# use postfix_macros::{postfix_macros, min_max};
+# postfix_macros! {
+let samples = vec![3.5, -1.0, 7.25, 0.0];
+assert_eq!(samples.iter().min_max!(), Some((&-1.0, &7.25)));
+let empty :Vec<u8> = Vec::new();
+assert_eq!(empty.into_iter().min_max!(), None);
+# }
+```
+*/
+#[macro_export]
+macro_rules! min_max {
+	($v:expr) => {
+		$crate::__private::min_max($v)
+	};
+}
+
+/**
+Finds the minimum and maximum of an iterator by a key in a single pass
+
+Meant to be used in a postfix context.
+Like [`min_max!`], but compares the elements by
+the key returned by the given closure.
+
+```
+# use postfix_macros::{postfix_macros, min_max_by_key};
+# postfix_macros! {
+struct Sample {
+	ts :u64,
+	value :f32,
+}
+let samples = vec![Sample { ts : 20, value : 1.0 }, Sample { ts : 10, value : 2.0 }];
+let (first, last) = samples.iter().min_max_by_key!(|s| s.ts).unwrap();
+assert_eq!((first.value, last.value), (2.0, 1.0));
+# }
+```
+*/
+#[macro_export]
+macro_rules! min_max_by_key {
+	($v:expr, $f:expr $(,)?) => {
+		$crate::__private::min_max_by_key($v, $f)
+	};
+}
+
+/**
+Reports the time an expression took to evaluate
+
+Implementation detail of [`time!`].
+Prints to stderr, unless the `time-log` or `time-tracing`
+features route the report to `log` or `tracing` instead.
+*/
+#[cfg(not(any(feature = "time-log", feature = "time-tracing")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __time_report {
+	($label:expr, $elapsed:expr) => {
+		eprintln!("[{}:{}:{}] {} took {:?}", file!(), line!(), column!(), $label, $elapsed)
+	};
+}
+
+/**
+Reports the time an expression took to evaluate
+
+Implementation detail of [`time!`].
+*/
+#[cfg(all(feature = "time-log", not(feature = "time-tracing")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __time_report {
+	($label:expr, $elapsed:expr) => {
+		$crate::__private::log::info!("[{}:{}:{}] {} took {:?}",
+			file!(), line!(), column!(), $label, $elapsed)
+	};
+}
+
+/**
+Reports the time an expression took to evaluate
+
+Implementation detail of [`time!`].
+*/
+#[cfg(feature = "time-tracing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __time_report {
+	($label:expr, $elapsed:expr) => {
+		$crate::__private::tracing::info!("[{}:{}:{}] {} took {:?}",
+			file!(), line!(), column!(), $label, $elapsed)
+	};
+}
+
+/**
+Measures the time the argument takes to evaluate
+
+Meant to be used in a postfix context.
+Evaluates the argument, and reports the elapsed
+wall-clock time together with the label, or the
+stringified argument if no label is given,
+and the location of the invocation.
+Evaluates to the argument.
+
+The report is printed to stderr by default.
+With the `time-log` feature, it is logged at the info
+level via `log` instead, and with the `time-tracing`
+feature, it is emitted as an info event via `tracing`.
+
+```
+# use postfix_macros::{postfix_macros, time};
+# postfix_macros! {
+fn build_index(docs :&[&str]) -> Vec<usize> {
+	docs.iter().map(|d| d.len()).collect()
+}
+let docs = ["a", "bc"];
+let index = build_index(&docs).time!("index build");
+assert_eq!(index, [1, 2]);
+# }
+```
+*/
+#[macro_export]
+macro_rules! time {
+	($v:expr) => {
+		$crate::time!($v, stringify!($v))
+	};
+	($v:expr, $label:expr $(,)?) => {{
+		let start = std::time::Instant::now();
+		let v = $v;
+		$crate::__time_report!($label, start.elapsed());
+		v
+	}};
+}
+
+/**
+Runs a closure repeatedly, printing timing statistics
+
+Meant to be used in a postfix context, for quick
+in-situ measurements without a benchmark harness.
+Takes the number of runs, calls the closure passed as
+argument that many times, and prints the minimum,
+average, and maximum time of a run to stderr,
+together with the location of the invocation.
+The results of the closure are passed through
+[`std::hint::black_box`] so that the calls
+aren't optimized away.
+Evaluates to `()`.
+
+Requires Rust 1.66 or later.
+
+```
+# use postfix_macros::{postfix_macros, timeit_n};
+# postfix_macros! {
+let input = "1,2,3,4";
+(|| input.split(',').map(|s| s.parse::<u32>().unwrap()).sum::<u32>()).timeit_n!(1000);
+# }
+```
+*/
+#[macro_export]
+macro_rules! timeit_n {
+	($v:expr, $n:expr $(,)?) => {{
+		let mut f = $v;
+		let n :u32 = $n;
+		let mut min = std::time::Duration::MAX;
+		let mut max = std::time::Duration::ZERO;
+		let mut total = std::time::Duration::ZERO;
+		for _ in 0..n {
+			let start = std::time::Instant::now();
+			std::hint::black_box(f());
+			let elapsed = start.elapsed();
+			min = std::cmp::min(min, elapsed);
+			max = std::cmp::max(max, elapsed);
+			total += elapsed;
+		}
+		if n > 0 {
+			eprintln!("[{}:{}:{}] {} runs: min {:?}, avg {:?}, max {:?}",
+				file!(), line!(), column!(), n, min, total / n, max);
+		}
+	}};
+}
+
+/**
+Prints the time elapsed since an `Instant`
+
+Meant to be used in a postfix context.
+Takes an [`Instant`](std::time::Instant) as argument,
+and prints the time elapsed since then to stderr,
+together with the label, or the stringified argument
+if no label is given, and the location of the invocation.
+Evaluates to the elapsed [`Duration`](std::time::Duration),
+so that it can also be recorded.
+
+```
+# use postfix_macros::{postfix_macros, elapsed_dbg};
+# postfix_macros! {
+let start = std::time::Instant::now();
+// ... perform the handshake
+let took = start.elapsed_dbg!("handshake");
+assert!(took.as_secs() < 60);
+# }
+```
+*/
+#[macro_export]
+macro_rules! elapsed_dbg {
+	($v:expr) => {
+		$crate::elapsed_dbg!($v, stringify!($v))
+	};
+	($v:expr, $label:expr $(,)?) => {
+		match std::time::Instant::elapsed(&$v) {
+			elapsed => {
+				eprintln!("[{}:{}:{}] {}: {:?} elapsed", file!(), line!(), column!(), $label, elapsed);
+				elapsed
+			},
+		}
+	};
+}
+
+/**
+Hides the argument from the optimizer
+
+Meant to be used in a postfix context, e.g. inside
+benchmarks, to keep values from being optimized away.
+Evaluates to [`std::hint::black_box`] applied to the argument.
+
+Requires Rust 1.66 or later.
+
+```
+# use postfix_macros::{postfix_macros, black_box};
+# postfix_macros! {
+let candidate = 97u64;
+let is_prime = (2..candidate).all(|d| candidate.black_box!() % d != 0);
+assert!(is_prime);
+# }
+```
+*/
+#[macro_export]
+macro_rules! black_box {
+	($v:expr) => {
+		std::hint::black_box($v)
+	};
+}
+
+/**
+Counts how often the argument is evaluated
+
+Meant to be used in a postfix context, for quick
+frequency profiling.
+Increments a counter for the invocation each time
+the argument is evaluated, and evaluates to the argument.
+The counters are atomic, so they are cheap to update
+and can be used from multiple threads.
+
+The counts can be obtained via [`call_counts!`],
+labeled with the given name, or the stringified
+argument if no name is given.
+
+Requires Rust 1.70 or later.
+
+```
+# use postfix_macros::{postfix_macros, count_calls, call_counts};
+# postfix_macros! {
+fn parse_header(line :&str) -> Option<&str> {
+	line.strip_prefix("# ").count_calls!("parse_header")
+}
+for line in &["# a", "b", "# c"] {
+	parse_header(line);
+}
+assert!(call_counts!().contains(&("parse_header", 3)));
+# }
+```
+*/
+#[macro_export]
+macro_rules! count_calls {
+	($v:expr) => {
+		$crate::count_calls!($v, stringify!($v))
+	};
+	($v:expr, $name:expr $(,)?) => {{
+		static COUNT :std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+		if COUNT.fetch_add(1, std::sync::atomic::Ordering::Relaxed) == 0 {
+			$crate::__private::register_call_counter($name, &COUNT);
+		}
+		$v
+	}};
+}
+
+/**
+Obtains the counts of the [`count_calls!`] invocations
+
+Evaluates to a `Vec<(&'static str, usize)>` of the names
+and counts of all [`count_calls!`] invocations that
+have been evaluated at least once, sorted by name.
+
+Requires Rust 1.70 or later.
+
+```
+# use postfix_macros::{postfix_macros, count_calls, call_counts};
+# postfix_macros! {
+for i in 0..4 {
+	if i % 2 == 0 {
+		i.count_calls!("even");
+	}
+}
+for (name, count) in call_counts!() {
+	eprintln!("{}: {}", name, count);
+}
+# }
+```
+*/
+#[macro_export]
+macro_rules! call_counts {
+	() => {
+		$crate::__private::call_counts()
+	};
+}
+
+/**
+Prints the argument at most once per interval
+
+Meant to be used in a postfix context, to keep hot
+code instrumented without flooding stderr.
+
+Prints the argument to stderr, together with the location
+of the invocation, if nothing has been printed by the invocation
+during the given interval, which is a [`std::time::Duration`].
+The argument is printed using the given format string,
+or with its `Debug` representation if none is given.
+Evaluates to the argument.
+
+Requires Rust 1.63 or later.
+
+```
+# use postfix_macros::{postfix_macros, throttle_log};
+# postfix_macros! {
+use std::time::Duration;
+let mut sum = 0;
+for queue_depth in 0..1000 {
+	// Only the first iteration prints
+	sum += queue_depth.throttle_log!(Duration::from_secs(5), "queue depth = {}");
+}
+assert_eq!(sum, 499500);
+# }
+```
+*/
+#[macro_export]
+macro_rules! throttle_log {
+	($v:expr, $interval:expr) => {
+		$crate::throttle_log!($v, $interval, concat!(stringify!($v), " = {:?}"))
+	};
+	($v:expr, $interval:expr, $fmt:expr $(,)?) => {
+		match $v {
+			v => {
+				static LAST :std::sync::Mutex<Option<std::time::Instant>> = std::sync::Mutex::new(None);
+				let now = std::time::Instant::now();
+				let mut last = LAST.lock().unwrap_or_else(|e| e.into_inner());
+				if last.map_or(true, |last| now.duration_since(last) >= $interval) {
+					*last = Some(now);
+					eprintln!(concat!("[{}:{}:{}] ", $fmt), file!(), line!(), column!(), v);
+				}
+				v
+			}
+		}
+	};
+}
+
+/**
+Hints that the argument is likely `true`
+
+Meant to be used in a postfix context, on conditions of branches.
+Evaluates to the argument, which needs to be a `bool`, and marks the
+path on which it is `false` as cold, so that the optimizer
+lays out the code to favour the path on which it is `true`.
+
+This is only a hint, and doesn't change the behaviour of the code.
+
+```
+# use postfix_macros::{postfix_macros, likely};
+# postfix_macros! {
+let data = [3, 1, 4, 1, 5];
+let mut sum = 0;
+for v in data.iter() {
+	let in_range = *v < 100;
+	if in_range.likely!() {
+		sum += v;
+	}
+}
+assert_eq!(sum, 14);
+# }
+```
+*/
+#[macro_export]
+macro_rules! likely {
+	($v:expr) => {
+		match $v {
+			v => {
+				let v :bool = v;
+				if !v {
+					$crate::__private::cold();
+				}
+				v
+			},
+		}
+	};
+}
+
+/**
+Hints that the argument is likely `false`
+
+Meant to be used in a postfix context, on conditions of branches.
+Evaluates to the argument, which needs to be a `bool`, and marks the
+path on which it is `true` as cold, so that the optimizer
+lays out the code to favour the path on which it is `false`.
+
+This is only a hint, and doesn't change the behaviour of the code.
+
+```
+# use postfix_macros::{postfix_macros, unlikely};
+# postfix_macros! {
+let cache = [Some(1), Some(2), None, Some(4)];
+let mut misses = 0;
+for entry in cache.iter() {
+	let cache_miss = entry.is_none();
+	if cache_miss.unlikely!() {
+		misses += 1;
+	}
+}
+assert_eq!(misses, 1);
+# }
+```
+*/
+#[macro_export]
+macro_rules! unlikely {
+	($v:expr) => {
+		match $v {
+			v => {
+				let v :bool = v;
+				if v {
+					$crate::__private::cold();
+				}
+				v
+			},
+		}
+	};
+}
+
+/**
+Marks the evaluation of the argument as cold
+
+Meant to be used in a postfix context, on blocks
+that are rarely executed, like error handling.
+Evaluates to the argument, and hints to the optimizer
+that the path leading to it is unlikely to be taken.
+
+This is only a hint, and doesn't change the behaviour of the code.
+
+```
+# use postfix_macros::{postfix_macros, cold_path};
+# postfix_macros! {
+fn fallback(s :&str) -> u32 {
+	eprintln!("invalid number: {}", s);
+	0
+}
+fn parse(s :&str) -> u32 {
+	match s.parse() {
+		Ok(v) => v,
+		Err(_) => fallback(s).cold_path!(),
+	}
+}
+assert_eq!(parse("42"), 42);
+assert_eq!(parse("x"), 0);
+# }
+```
+*/
+#[macro_export]
+macro_rules! cold_path {
+	($v:expr) => {{
+		$crate::__private::cold();
+		$v
+	}};
+}
+
+/**
+Awaits a future with a timeout
+
+Meant to be used in a postfix context, inside async code.
+Wraps the future via [`tokio::time::timeout`] and awaits it,
+evaluating to `Ok` of the output of the future if it
+completes within the given [`std::time::Duration`],
+and to `Err` of [`tokio::time::error::Elapsed`] otherwise.
+Requires the `tokio` feature.
+
+```
+# use postfix_macros::{postfix_macros, await_timeout};
+use std::time::Duration;
+use tokio::time::error::Elapsed;
+# postfix_macros! {
+async fn fetch(url :&str) -> usize { url.len() }
+async fn run() -> Result<usize, Elapsed> {
+	let len = fetch("https://example.com").await_timeout!(Duration::from_secs(5))?;
+	Ok(len)
+}
+# let rt = tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap();
+# assert_eq!(rt.block_on(run()), Ok(19));
+# }
+```
+*/
+#[cfg(feature = "tokio")]
+#[macro_export]
+macro_rules! await_timeout {
+	($v:expr, $duration:expr $(,)?) => {
+		$crate::__private::tokio::time::timeout($duration, $v).await
+	};
+}
+
+/**
+Runs a future to completion on the current thread
+
+Implementation detail of [`block_on!`].
+*/
+#[cfg(feature = "futures")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __block_on {
+	($v:expr) => {
+		$crate::__private::futures::executor::block_on($v)
+	};
+}
+
+/**
+Runs a future to completion on the current thread
+
+Implementation detail of [`block_on!`].
+*/
+#[cfg(all(feature = "tokio", not(feature = "futures")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __block_on {
+	($v:expr) => {
+		$crate::__private::tokio::runtime::Builder::new_current_thread()
+			.enable_all()
+			.build()
+			.expect("failed to build tokio runtime")
+			.block_on($v)
+	};
+}
+
+/**
+Runs a future to completion on the current thread
+
+Meant to be used in a postfix context, at the boundary
+between sync and async code, e.g. in tests.
+Blocks the current thread until the future has completed,
+and evaluates to its output.
+
+Without further arguments, the future is run via
+[`futures::executor::block_on`] if the `futures` feature
+is enabled. Otherwise, with the `tokio` feature, it is run on
+a new current-thread tokio runtime, built for this call.
+Alternatively, a tokio [`Runtime`] or [`Handle`]
+can be passed, on which the future is then run.
+
+[`Runtime`]: https://docs.rs/tokio/1/tokio/runtime/struct.Runtime.html
+[`Handle`]: https://docs.rs/tokio/1/tokio/runtime/struct.Handle.html
+
+```
+# use postfix_macros::{postfix_macros, block_on};
+# postfix_macros! {
+async fn async_init() -> Vec<u32> { vec![1, 2, 3] }
+let v = async_init().block_on!();
+assert_eq!(v, [1, 2, 3]);
+
+let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+let v = async_init().block_on!(rt);
+assert_eq!(v, [1, 2, 3]);
+# }
+```
+*/
+#[cfg(any(feature = "futures", feature = "tokio"))]
+#[macro_export]
+macro_rules! block_on {
+	($v:expr) => {
+		$crate::__block_on!($v)
+	};
+	($v:expr, $rt:expr $(,)?) => {
+		$rt.block_on($v)
+	};
+}
+
+/**
+Spawns a future as a task on the async runtime
+
+Implementation detail of [`spawn_task!`] and [`spawn_named!`].
+*/
+#[cfg(feature = "tokio")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __spawn {
+	($v:expr) => {
+		$crate::__private::tokio::spawn($v)
+	};
+	($v:expr, $name:expr) => {{
+		// Task names are only supported with tokio_unstable
+		let _ = $name;
+		$crate::__private::tokio::spawn($v)
+	}};
+}
+
+/**
+Spawns a future as a task on the async runtime
+
+Implementation detail of [`spawn_task!`] and [`spawn_named!`].
+*/
+#[cfg(all(feature = "async-std", not(feature = "tokio")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __spawn {
+	($v:expr) => {
+		$crate::__private::async_std::task::spawn($v)
+	};
+	($v:expr, $name:expr) => {
+		$crate::__private::async_std::task::Builder::new()
+			.name(String::from($name))
+			.spawn($v)
+			.expect("cannot spawn task")
+	};
+}
+
+/**
+Spawns the argument as a task
+
+Meant to be used in a postfix context, on futures.
+Spawns the future as a task on the async runtime,
+and evaluates to the `JoinHandle` of the task.
+
+Requires the `tokio` or the `async-std` feature,
+spawning the task via `tokio::spawn` or
+`async_std::task::spawn`, respectively.
+If both features are enabled, tokio is used.
+
+```
+# use postfix_macros::{postfix_macros, spawn_task};
+# postfix_macros! {
+async fn process(msg :u32) -> u32 { msg * 2 }
+async fn run() {
+	let handle = process(21).spawn_task!();
+	# #[cfg(feature = "tokio")]
+	# assert_eq!(handle.await.unwrap(), 42);
+	# #[cfg(not(feature = "tokio"))]
+	# assert_eq!(handle.await, 42);
+}
+# #[cfg(feature = "tokio")]
+# tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(run());
+# #[cfg(not(feature = "tokio"))]
+# async_std::task::block_on(run());
+# }
+```
+*/
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+#[macro_export]
+macro_rules! spawn_task {
+	($v:expr) => {
+		$crate::__spawn!($v)
+	};
+}
+
+/**
+Spawns the argument as a named task
+
+Meant to be used in a postfix context, on futures.
+Like [`spawn_task!`], but attaches the given name to the task
+if the runtime supports it, so that it shows up in diagnostics.
+Evaluates to the `JoinHandle` of the task.
+
+With async-std, the name is set via `async_std::task::Builder`.
+tokio only supports task names when built with the
+`tokio_unstable` cfg, so the name is ignored there.
+
+```
+# use postfix_macros::{postfix_macros, spawn_named};
+# postfix_macros! {
+async fn worker(id :u32) -> u32 { id }
+async fn run() {
+	let handle = worker(7).spawn_named!("worker");
+	# #[cfg(feature = "tokio")]
+	# assert_eq!(handle.await.unwrap(), 7);
+	# #[cfg(not(feature = "tokio"))]
+	# assert_eq!(handle.await, 7);
+}
+# #[cfg(feature = "tokio")]
+# tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(run());
+# #[cfg(not(feature = "tokio"))]
+# async_std::task::block_on(run());
+# }
+```
+*/
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+#[macro_export]
+macro_rules! spawn_named {
+	($v:expr, $name:expr $(,)?) => {
+		$crate::__spawn!($v, $name)
+	};
+}
+
+/**
+Pins and boxes a future into a trait object
+
+Meant to be used in a postfix context, e.g. when returning
+futures from trait methods or storing them in collections.
+Evaluates to a `Pin<Box<dyn Future<Output = T> + Send + '_>>`,
+which is the same type as the `BoxFuture` of the `futures` crate.
+
+For futures that are not `Send`, pass `local` to get a
+`Pin<Box<dyn Future<Output = T> + '_>>` instead,
+which is the same type as `LocalBoxFuture`.
+
+```
+# use postfix_macros::{postfix_macros, boxed_future};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+# postfix_macros! {
+struct Request { id :u32 }
+async fn handler(req :Request) -> u32 { req.id }
+
+let futures = vec![
+	handler(Request { id : 1 }).boxed_future!(),
+	handler(Request { id : 2 }).boxed_future!(),
+];
+# drop(futures);
+
+async fn local_handler(shared :Rc<u32>) -> u32 { *shared }
+let fut :Pin<Box<dyn Future<Output = u32>>> = local_handler(Rc::new(3)).boxed_future!(local);
+# drop(fut);
+# }
+```
+*/
+#[macro_export]
+macro_rules! boxed_future {
+	($v:expr) => {{
+		let f :std::pin::Pin<Box<dyn std::future::Future<Output = _> + Send + '_>> = Box::pin($v);
+		f
+	}};
+	($v:expr, local $(,)?) => {{
+		let f :std::pin::Pin<Box<dyn std::future::Future<Output = _> + '_>> = Box::pin($v);
+		f
+	}};
+}
+
+/**
+Awaits whichever of two futures completes first
+
+Meant to be used in a postfix context, inside async code.
+Polls the future and the given other future concurrently
+via [`futures::future::select`], and evaluates to
+`Either::Left` of the output of the future or `Either::Right`
+of the output of the other future, whichever completes first.
+The remaining future is dropped.
+If both are ready at the same time, the receiver wins.
+Requires the `futures` feature.
+
+```
+# use postfix_macros::{postfix_macros, race_with, block_on};
+use futures::future::{pending, Either};
+# postfix_macros! {
+async fn primary_fetch() -> u32 { pending().await }
+async fn fallback_fetch() -> &'static str { "fallback" }
+async fn fetch() -> String {
+	match primary_fetch().race_with!(fallback_fetch()) {
+		Either::Left(v) => v.to_string(),
+		Either::Right(v) => v.to_string(),
+	}
+}
+assert_eq!(fetch().block_on!(), "fallback");
+# }
+```
+*/
+#[cfg(feature = "futures")]
+#[macro_export]
+macro_rules! race_with {
+	($v:expr, $other:expr $(,)?) => {{
+		let left = $v;
+		let right = $other;
+		$crate::__private::futures::pin_mut!(left, right);
+		match $crate::__private::futures::future::select(left, right).await {
+			$crate::__private::futures::future::Either::Left((v, _)) => {
+				$crate::__private::futures::future::Either::Left(v)
+			},
+			$crate::__private::futures::future::Either::Right((v, _)) => {
+				$crate::__private::futures::future::Either::Right(v)
+			},
+		}
+	}};
+}
+
+/**
+Sleeps asynchronously for the given duration
+
+Implementation detail of [`retry_async!`].
+*/
+#[cfg(feature = "tokio")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __sleep_async {
+	($duration:expr) => {
+		$crate::__private::tokio::time::sleep($duration).await
+	};
+}
+
+/**
+Sleeps asynchronously for the given duration
+
+Implementation detail of [`retry_async!`].
+*/
+#[cfg(all(feature = "async-std", not(feature = "tokio")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __sleep_async {
+	($duration:expr) => {
+		$crate::__private::async_std::task::sleep($duration).await
+	};
+}
+
+/**
+Sleeps asynchronously for the given duration
+
+Implementation detail of [`retry_async!`].
+*/
+#[cfg(not(any(feature = "tokio", feature = "async-std")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __sleep_async {
+	($duration:expr) => {
+		compile_error!("backoff in retry_async! requires the `tokio` or the `async-std` feature")
+	};
+}
+
+/**
+Re-awaits a fallible future until it succeeds
+
+Meant to be used in a postfix context, inside async code.
+The async variant of [`retry!`].
+
+Awaits the argument, which must be a future of a `Result`,
+up to the given number of times, until it is `Ok`.
+The macro evaluates to the first `Ok` value, or
+to the last error if all attempts have failed.
+
+As the macro sees the tokens of the argument, the
+argument expression itself is re-evaluated each time,
+creating a new future for each attempt.
+Alternatively, a closure literal returning a future can be
+passed as the argument, which is then called for each attempt.
+To call any other callable argument, like a variable
+holding a closure, pass `call` before the number of attempts.
+
+Optionally, a `backoff = duration` parameter can be
+passed, with a [`Duration`](std::time::Duration)
+to sleep between attempts, or `backoff = exp(duration)`
+to double the duration after each attempt.
+Sleeping requires the `tokio` or the `async-std` feature.
+
+```
+# use postfix_macros::{postfix_macros, retry_async};
+use std::cell::Cell;
+# postfix_macros! {
+async fn fetch(calls :&Cell<u32>) -> Result<u32, u32> {
+	calls.set(calls.get() + 1);
+	if calls.get() < 3 { Err(calls.get()) } else { Ok(calls.get()) }
+}
+async fn fetch_with_retries() -> Result<u32, u32> {
+	let calls = Cell::new(0);
+	(|| fetch(&calls)).retry_async!(3)
+}
+# drop(fetch_with_retries());
+# }
+```
+*/
+#[macro_export]
+macro_rules! retry_async {
+	(@parse ($($body:tt)*) $n:expr) => {
+		$crate::retry_async!(@run ($($body)*) $n)
+	};
+	(@parse ($($body:tt)*) $n:expr, backoff = exp($backoff:expr)) => {
+		$crate::retry_async!(@run ($($body)*) $n, $backoff, 2)
+	};
+	(@parse ($($body:tt)*) $n:expr, backoff = $backoff:expr) => {
+		$crate::retry_async!(@run ($($body)*) $n, $backoff, 1)
+	};
+	(@run ($($body:tt)*) $n:expr) => {{
+		let attempts :u32 = $n;
+		let mut attempt = 1;
+		loop {
+			match $($body)*.await {
+				Ok(v) => break Ok(v),
+				Err(e) => {
+					if attempt >= attempts {
+						break Err(e);
+					}
+					attempt += 1;
+				},
+			}
+		}
+	}};
+	(@run ($($body:tt)*) $n:expr, $backoff:expr, $factor:expr) => {{
+		let attempts :u32 = $n;
+		let mut attempt = 1;
+		let mut backoff : ::std::time::Duration = $backoff;
+		loop {
+			match $($body)*.await {
+				Ok(v) => break Ok(v),
+				Err(e) => {
+					if attempt >= attempts {
+						break Err(e);
+					}
+					attempt += 1;
+					$crate::__sleep_async!(backoff);
+					backoff *= $factor;
+				},
+			}
+		}
+	}};
+	({ ( || $($closure:tt)* ) }, $($args:tt)*) => {
+		$crate::retry_async!(@parse ((|| $($closure)*)()) $($args)*)
+	};
+	({ ( move || $($closure:tt)* ) }, $($args:tt)*) => {
+		$crate::retry_async!(@parse ((move || $($closure)*)()) $($args)*)
+	};
+	($v:expr, call, $($args:tt)*) => {
+		$crate::retry_async!(@parse (($v)()) $($args)*)
+	};
+	($v:expr, $($args:tt)*) => {
+		$crate::retry_async!(@parse ($v) $($args)*)
+	};
+}
+
+/**
+Awaits a future unless it gets cancelled
+
+Meant to be used in a postfix context, inside async code.
+Runs the future until it completes or the given
+[`CancellationToken`] is cancelled, whichever happens first,
+via [`CancellationToken::run_until_cancelled`].
+Evaluates to `Some` of the output of the future if it
+completes, and to `None` if it gets cancelled,
+in which case the future is dropped.
+Requires the `tokio-util` feature.
+
+[`CancellationToken`]: https://docs.rs/tokio-util/0.7/tokio_util/sync/struct.CancellationToken.html
+[`CancellationToken::run_until_cancelled`]: https://docs.rs/tokio-util/0.7/tokio_util/sync/struct.CancellationToken.html#method.run_until_cancelled
+
+```
+# use postfix_macros::{postfix_macros, with_cancellation};
+use tokio_util::sync::CancellationToken;
+# postfix_macros! {
+async fn long_job() -> u32 { 42 }
+async fn run(token :CancellationToken) -> Option<u32> {
+	long_job().with_cancellation!(token)
+}
+let token = CancellationToken::new();
+let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+assert_eq!(rt.block_on(run(token.clone())), Some(42));
+token.cancel();
+assert_eq!(rt.block_on(run(token)), None);
+# }
+```
+*/
+#[cfg(feature = "tokio-util")]
+#[macro_export]
+macro_rules! with_cancellation {
+	($v:expr, $token:expr $(,)?) => {
+		$crate::__private::tokio_util::sync::CancellationToken::run_until_cancelled(&$token, $v).await
+	};
+}
+
+/**
+Asserts a snapshot via the given insta macro
+
+Implementation detail of [`assert_snapshot!`].
+*/
+#[cfg(feature = "insta")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __insta_snapshot {
+	($mac:ident, $v:expr) => {
+		$crate::__private::insta::$mac!($v)
+	};
+	($mac:ident, $v:expr, $name:expr $(,)?) => {
+		$crate::__private::insta::$mac!($name, $v)
+	};
+}
+
+/**
+Asserts a snapshot of the argument
+
+Meant to be used in a postfix context, in tests,
+to end a chain in a snapshot assertion via the
+[`insta`](https://docs.rs/insta) crate.
+Requires the `insta` feature.
+
+By default, the argument is snapshotted via its `Display`
+implementation, like `insta::assert_snapshot!` does.
+Pass `debug` to snapshot its `Debug` representation instead,
+like `insta::assert_debug_snapshot!`, or `json` to snapshot
+it serialized to JSON, like `insta::assert_json_snapshot!`.
+
+Optionally, the name of the snapshot can be passed,
+otherwise it's derived from the name of the test.
+Inline snapshots are not supported, as all invocations
+inside a [`postfix_macros!`] block share its location.
+
+```no_run
+# use postfix_macros::{postfix_macros, assert_snapshot};
+# postfix_macros! {
+struct Page { title :&'static str }
+fn render(page :Page) -> String { format!("<h1>{}</h1>", page.title) }
+render(Page { title : "Home" }).assert_snapshot!("homepage");
+vec![1, 2].assert_snapshot!(json);
+# }
+```
+*/
+#[cfg(feature = "insta")]
+#[macro_export]
+macro_rules! assert_snapshot {
+	($v:expr, debug $(, $($args:tt)*)?) => {
+		$crate::__insta_snapshot!(assert_debug_snapshot, $v $(, $($args)*)?)
+	};
+	($v:expr, json $(, $($args:tt)*)?) => {
+		$crate::__insta_snapshot!(assert_json_snapshot, $v $(, $($args)*)?)
+	};
+	($v:expr $(, $($args:tt)*)?) => {
+		$crate::__insta_snapshot!(assert_snapshot, $v $(, $($args)*)?)
+	};
+}
+
+/**
+Asserts that the argument matches a pattern, proptest style
+
+Meant to be used in a postfix context, inside
+[`proptest!`](https://docs.rs/proptest/1/proptest/macro.proptest.html) bodies.
+Like `prop_assert!(matches!(..))`, if the argument
+doesn't match the pattern, returns a `TestCaseError`
+with the `Debug` representation of the argument
+from the surrounding function, instead of panicking,
+so that proptest can shrink the input.
+Requires the `proptest` feature.
+
+```
+# use postfix_macros::{postfix_macros, prop_assert_matches};
+use proptest::prelude::*;
+# postfix_macros! {
+proptest! {
+	# /*
+	#[test]
+	# */
+	fn parses_small_numbers(n in 0..1000u32) {
+		let parsed :Result<u32, _> = n.to_string().parse();
+		parsed.prop_assert_matches!(Ok(v) if v == n);
+	}
+}
+# parses_small_numbers();
+# }
+```
+*/
+#[cfg(feature = "proptest")]
+#[macro_export]
+macro_rules! prop_assert_matches {
+	($v:expr, $(|)? $($pat:pat)|+ $(if $guard:expr)? $(,)?) => {
+		match $v {
+			$($pat)|+ $(if $guard)? => (),
+			v => {
+				return Err($crate::__private::proptest::test_runner::TestCaseError::fail(format!(
+					"assertion failed: `{:?}` does not match `{}` at {}:{}",
+					v, stringify!($($pat)|+ $(if $guard)?), file!(), line!())));
+			},
+		}
+	};
+}
+
+/**
+Unwraps an `Ok`, proptest style
+
+Meant to be used in a postfix context, inside
+[`proptest!`](https://docs.rs/proptest/1/proptest/macro.proptest.html) bodies.
+Evaluates to the `Ok` value of the argument,
+which must be a `Result`. If it is `Err`,
+returns a `TestCaseError` with the `Debug` representation
+of the error from the surrounding function,
+instead of panicking, so that proptest can shrink the input.
+Requires the `proptest` feature.
+
+```
+# use postfix_macros::{postfix_macros, prop_assert_ok};
+use proptest::prelude::*;
+# postfix_macros! {
+proptest! {
+	# /*
+	#[test]
+	# */
+	fn roundtrips(n :i64) {
+		let parsed :Result<i64, _> = n.to_string().parse();
+		prop_assert_eq!(parsed.prop_assert_ok!(), n);
+	}
+}
+# roundtrips();
+# }
+```
+*/
+#[cfg(feature = "proptest")]
+#[macro_export]
+macro_rules! prop_assert_ok {
+	($v:expr) => {
+		match $v {
+			Ok(v) => v,
+			Err(e) => {
+				return Err($crate::__private::proptest::test_runner::TestCaseError::fail(format!(
+					"assertion failed: `{:?}` is not `Ok` at {}:{}",
+					e, file!(), line!())));
+			},
+		}
+	};
+}
+
+/**
+Asserts that the argument equals the expected value, with a diff
+
+Meant to be used in a postfix context, in tests.
+Like `assert_eq!`, but via `pretty_assertions::assert_eq!`,
+which prints a colored diff of the `Debug` representations
+of both values on failure, making it easier to spot the
+differences in large structs.
+Optionally, a message with format arguments can be passed.
+Requires the `pretty_assertions` feature.
+
+```
+# use postfix_macros::{postfix_macros, assert_eq_pretty};
+# postfix_macros! {
+fn result() -> Vec<(&'static str, u32)> { vec![("a", 1), ("b", 2)] }
+result().assert_eq_pretty!(vec![("a", 1), ("b", 2)]);
+result().len().assert_eq_pretty!(2, "unexpected length of {:?}", result());
+# }
+```
+*/
+#[cfg(feature = "pretty_assertions")]
+#[macro_export]
+macro_rules! assert_eq_pretty {
+	($v:expr, $expected:expr $(, $($arg:tt)*)?) => {
+		$crate::__private::pretty_assertions::assert_eq!($v, $expected $(, $($arg)*)?)
+	};
+}
+
+/**
+Checks whether the argument approximately equals the expected value
+
+Meant to be used in a postfix context, on floats
+or other types implementing the traits of the
+[`approx`](https://docs.rs/approx) crate.
+Evaluates to `true` if the argument and the expected
+value differ by at most the given `epsilon`,
+via `approx::abs_diff_eq!`, or, if a `max_relative`
+is given, if they are relatively equal,
+via `approx::relative_eq!`.
+Without an `epsilon`, the default of the type is used.
+Requires the `approx` feature.
+
+```
+# use postfix_macros::{postfix_macros, approx_eq};
+# postfix_macros! {
+let measured = 0.1 + 0.2;
+assert!(measured.approx_eq!(0.3, epsilon = 1e-6));
+assert!(!measured.approx_eq!(0.31, epsilon = 1e-6));
+assert!(1e9f64.approx_eq!(1.0000001e9, max_relative = 1e-6));
+# }
+```
+*/
+#[cfg(feature = "approx")]
+#[macro_export]
+macro_rules! approx_eq {
+	($v:expr, $expected:expr $(,)?) => {
+		$crate::__private::approx::abs_diff_eq!($v, $expected)
+	};
+	($v:expr, $expected:expr, epsilon = $epsilon:expr $(,)?) => {
+		$crate::__private::approx::abs_diff_eq!($v, $expected, epsilon = $epsilon)
+	};
+	($v:expr, $expected:expr, $(epsilon = $epsilon:expr,)? max_relative = $max_relative:expr $(,)?) => {
+		$crate::__private::approx::relative_eq!($v, $expected $(, epsilon = $epsilon)?, max_relative = $max_relative)
+	};
+}
+
+#[doc(hidden)]
+pub mod __private {
+	use std::any::Any;
+	use std::collections::HashMap;
+	use std::collections::hash_map::DefaultHasher;
+	use std::fmt::{Display, Write};
+	use std::hash::{Hash, Hasher};
+	use std::sync::Mutex;
+
+	/// Wrapper to select the most powerful way of
+	/// formatting an error via autoref specialization
+	pub struct Report<'a, T :?Sized>(pub &'a T);
+
+	pub trait ReportViaError {
+		fn report(&self) -> String;
+	}
+
+	impl<'a, T :std::error::Error + ?Sized> ReportViaError for &Report<'a, T> {
+		fn report(&self) -> String {
+			let mut res = self.0.to_string();
+			let mut source = self.0.source();
+			while let Some(s) = source {
+				let _ = write!(res, "\nCaused by: {}", s);
+				source = s.source();
+			}
+			res
+		}
+	}
+
+	pub trait ReportViaDisplay {
+		fn report(&self) -> String;
+	}
+
+	impl<'a, T :Display + ?Sized> ReportViaDisplay for Report<'a, T> {
+		fn report(&self) -> String {
+			self.0.to_string()
+		}
+	}
+
+	pub use postfix_macros_impl::__implicit_binding as implicit_binding;
+
+	/// Calls the closure with the reference,
+	/// helping type inference of the closure's parameter
+	pub fn call_with_ref<T :?Sized, R, F :FnOnce(&T) -> R>(v :&T, f :F) -> R {
+		f(v)
+	}
+
+	/// Calls the function with the value,
+	/// helping type inference of closure parameters
+	pub fn call<T, R, F :FnOnce(T) -> R>(v :T, f :F) -> R {
+		f(v)
+	}
+
+	/// Guard that calls the function with the value when dropped
+	pub struct Defer<T, F :FnOnce(T)> {
+		inner :Option<(T, F)>,
+	}
+
+	impl<T, F :FnOnce(T)> Defer<T, F> {
+		pub fn new(v :T, f :F) -> Self {
+			Defer {
+				inner : Some((v, f)),
+			}
+		}
+	}
+
+	impl<T, F :FnOnce(T)> Drop for Defer<T, F> {
+		fn drop(&mut self) {
+			if let Some((v, f)) = self.inner.take() {
+				f(v);
+			}
+		}
+	}
+
+	/// Type erased cache for the `memoize` macro
+	#[derive(Default)]
+	pub struct MemoCache {
+		map :Mutex<HashMap<u64, Vec<Box<dyn Any + Send>>>>,
+	}
+
+	impl MemoCache {
+		pub fn get_or_insert_with<K, V, F>(&self, key :K, f :F) -> V
+		where
+			K :Hash + Eq + Send + 'static,
+			V :Clone + Send + 'static,
+			F :FnOnce() -> V,
+		{
+			let mut hasher = DefaultHasher::new();
+			key.hash(&mut hasher);
+			let hash = hasher.finish();
+			{
+				let map = self.map.lock().unwrap_or_else(|e| e.into_inner());
+				let entries = map.get(&hash).into_iter().flatten();
+				for entry in entries {
+					if let Some((k, v)) = entry.downcast_ref::<(K, V)>() {
+						if *k == key {
+							return v.clone();
+						}
+					}
+				}
+			}
+			// Don't hold the lock during evaluation,
+			// as it might be recursive
+			let v = f();
+			let mut map = self.map.lock().unwrap_or_else(|e| e.into_inner());
+			map.entry(hash)
+				.or_default()
+				.push(Box::new((key, v.clone())));
+			v
+		}
+	}
+
+	/// Truncates the string to at most `max_len` characters,
+	/// marking the truncation with an ellipsis
+	pub fn truncate(mut s :String, max_len :usize) -> String {
+		if let Some((idx, _)) = s.char_indices().nth(max_len) {
+			s.truncate(idx);
+			s.push('…');
+		}
+		s
+	}
+
+	/// Formats a string of binary digits, grouped
+	/// by nibbles, and followed by the indices of the set bits
+	pub fn bits_repr(bin :&str) -> String {
+		// Group from the right, so that a partial group ends up on the left
+		let mut groups = bin.as_bytes().rchunks(4)
+			.map(|g| String::from_utf8_lossy(g))
+			.collect::<Vec<_>>();
+		groups.reverse();
+		let mut res = format!("0b{}", groups.join("_"));
+		let set_bits = bin.bytes()
+			.enumerate()
+			.filter(|&(_, c)| c == b'1')
+			.map(|(i, _)| (bin.len() - 1 - i).to_string())
+			.collect::<Vec<_>>();
+		if set_bits.is_empty() {
+			res.push_str(" (no bits set)");
+		} else {
+			let _ = write!(res, " (bits {})", set_bits.join(","));
+		}
+		res
+	}
+
+	/// Formats the bytes in the classic offset/hex/ASCII format
+	pub fn hexdump(bytes :&[u8], limit :Option<usize>) -> String {
+		let shown = &bytes[..limit.unwrap_or(bytes.len()).min(bytes.len())];
+		let mut res = String::new();
+		for (i, line) in shown.chunks(16).enumerate() {
+			let _ = write!(res, "{:08x} ", i * 16);
+			for j in 0..16 {
+				if j == 8 {
+					res.push(' ');
+				}
+				match line.get(j) {
+					Some(b) => { let _ = write!(res, " {:02x}", b); },
+					None => res.push_str("   "),
+				}
+			}
+			res.push_str("  |");
+			res.extend(line.iter().map(|&b| if b.is_ascii_graphic() || b == b' ' {
+				b as char
+			} else {
+				'.'
+			}));
+			res.push_str("|\n");
+		}
+		if shown.len() < bytes.len() {
+			let _ = writeln!(res, "... ({} more bytes)", bytes.len() - shown.len());
+		}
+		res
+	}
+
+	/// Formats the bytes as lowercase hex
+	pub fn hex_lower(bytes :&[u8]) -> String {
+		let mut res = String::with_capacity(bytes.len() * 2);
+		for b in bytes {
+			let _ = write!(res, "{:02x}", b);
+		}
+		res
+	}
+
+	/// Returns the name of the referenced type
+	pub fn type_name_of<T :?Sized>(_v :&T) -> &'static str {
+		std::any::type_name::<T>()
+	}
+
+	#[cfg(postfix_macros_once_lock)]
+	static ERROR_REPORTER :std::sync::OnceLock<crate::ErrorReporter> = std::sync::OnceLock::new();
+
+	/// Registers the reporter if none was registered yet
+	#[cfg(postfix_macros_once_lock)]
+	pub fn set_error_reporter(reporter :crate::ErrorReporter) -> bool {
+		ERROR_REPORTER.set(reporter).is_ok()
+	}
+
+	/// Calls the registered reporter, if any
+	#[cfg(postfix_macros_once_lock)]
+	pub fn report_error<E :std::fmt::Debug>(err :&E, loc :&crate::ErrorLocation) {
+		if let Some(reporter) = ERROR_REPORTER.get() {
+			reporter(err, loc);
+		}
+	}
+
+	#[cfg(postfix_macros_once_lock)]
+	type CallCounters = Mutex<Vec<(&'static str, &'static std::sync::atomic::AtomicUsize)>>;
+
+	#[cfg(postfix_macros_once_lock)]
+	static CALL_COUNTERS :CallCounters = Mutex::new(Vec::new());
+
+	/// Registers the counter of a `count_calls!` invocation
+	#[cfg(postfix_macros_once_lock)]
+	pub fn register_call_counter(name :&'static str, count :&'static std::sync::atomic::AtomicUsize) {
+		CALL_COUNTERS.lock().unwrap_or_else(|e| e.into_inner()).push((name, count));
+	}
+
+	/// Returns the current counts of the registered counters, sorted by name
+	#[cfg(postfix_macros_once_lock)]
+	pub fn call_counts() -> Vec<(&'static str, usize)> {
+		let counters = CALL_COUNTERS.lock().unwrap_or_else(|e| e.into_inner());
+		let mut res = counters.iter()
+			.map(|(name, count)| (*name, count.load(std::sync::atomic::Ordering::Relaxed)))
+			.collect::<Vec<_>>();
+		res.sort_by_key(|(name, _)| *name);
+		res
+	}
+
+	/// Lossless conversion of unsigned integers into a byte count
+	pub trait ByteCount {
+		fn byte_count(self) -> u64;
+	}
+
+	macro_rules! impl_byte_count {
+		($($t:ty),*) => {
+			$(impl ByteCount for $t {
+				fn byte_count(self) -> u64 {
+					self as u64
+				}
+			})*
+		};
+	}
+
+	impl_byte_count!(u8, u16, u32, u64, usize);
+
+	/// Formats the byte count with one decimal place,
+	/// using the largest fitting unit
+	pub fn pretty_bytes(bytes :u64, base :u64, units :&[&str]) -> String {
+		if bytes < base {
+			return format!("{} {}", bytes, units[0]);
+		}
+		let mut value = bytes as f64;
+		let mut unit = 0;
+		while value >= base as f64 && unit + 1 < units.len() {
+			value /= base as f64;
+			unit += 1;
+		}
+		format!("{:.1} {}", value, units[unit])
+	}
+
+	/// Conversion of integers into the matching `NonZero*` type
+	pub trait IntoNonZero :Sized {
+		type NonZero;
+		fn into_nonzero(self) -> Option<Self::NonZero>;
+	}
+
+	macro_rules! impl_into_nonzero {
+		($($t:ty => $nz:ident),*) => {
+			$(impl IntoNonZero for $t {
+				type NonZero = std::num::$nz;
+				fn into_nonzero(self) -> Option<std::num::$nz> {
+					std::num::$nz::new(self)
+				}
+			})*
+		};
+	}
+
+	impl_into_nonzero!(
+		u8 => NonZeroU8, u16 => NonZeroU16, u32 => NonZeroU32,
+		u64 => NonZeroU64, u128 => NonZeroU128, usize => NonZeroUsize,
+		i8 => NonZeroI8, i16 => NonZeroI16, i32 => NonZeroI32,
+		i64 => NonZeroI64, i128 => NonZeroI128, isize => NonZeroIsize
+	);
+
+	/// Conversions between integer types with explicit overflow behaviour
+	pub trait IntCast<T> {
+		fn wrapping_cast(self) -> T;
+		fn saturating_cast(self) -> T;
+	}
+
+	macro_rules! impl_int_cast {
+		($($from:ident)*; $to:tt) => {
+			$(impl_int_cast!(@impl $from $to);)*
+		};
+		(@impl $from:ident [$($to:ident)*]) => {
+			$(impl IntCast<$to> for $from {
+				fn wrapping_cast(self) -> $to {
+					self as $to
+				}
+				#[allow(unknown_lints, clippy::legacy_numeric_constants)]
+				fn saturating_cast(self) -> $to {
+					use std::convert::TryFrom;
+					match $to::try_from(self) {
+						Ok(v) => v,
+						// Either too large or too small
+						Err(_) if self > 0 => $to::max_value(),
+						Err(_) => $to::min_value(),
+					}
+				}
+			})*
+		};
+	}
+
+	impl_int_cast!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize;
+		[u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize]);
+
+	/// Ranges that a value can be clamped into
+	pub trait ClampRange<T> {
+		fn clamp_value(&self, v :T) -> T;
+	}
+
+	impl<T :PartialOrd + Clone> ClampRange<T> for std::ops::RangeInclusive<T> {
+		fn clamp_value(&self, v :T) -> T {
+			if v < *self.start() {
+				self.start().clone()
+			} else if v > *self.end() {
+				self.end().clone()
+			} else {
+				v
+			}
+		}
+	}
+
+	impl<T :PartialOrd + Clone> ClampRange<T> for std::ops::RangeFrom<T> {
+		fn clamp_value(&self, v :T) -> T {
+			if v < self.start {
+				self.start.clone()
+			} else {
+				v
+			}
+		}
+	}
+
+	impl<T :PartialOrd + Clone> ClampRange<T> for std::ops::RangeToInclusive<T> {
+		fn clamp_value(&self, v :T) -> T {
+			if v > self.end {
+				self.end.clone()
+			} else {
+				v
+			}
+		}
+	}
+
+	/// Joins the strings with the separator
+	pub fn join_str<I>(it :I, sep :&str) -> String
+	where
+		I :IntoIterator,
+		I::Item :AsRef<str>,
+	{
+		let mut res = String::new();
+		for (i, s) in it.into_iter().enumerate() {
+			if i > 0 {
+				res.push_str(sep);
+			}
+			res.push_str(s.as_ref());
+		}
+		res
+	}
+
+	/// Finds the first minimum and last maximum
+	///
+	/// Only clones the element if there is just one,
+	/// as it's then both the minimum and the maximum.
+	pub fn min_max<I>(it :I) -> Option<(I::Item, I::Item)>
+	where
+		I :IntoIterator,
+		I::Item :Clone + PartialOrd,
+	{
+		let mut it = it.into_iter();
+		let mut min = it.next()?;
+		// None while the maximum is the first element, stored in min
+		let mut max = None;
+		for v in it {
+			if v < min {
+				let old_min = std::mem::replace(&mut min, v);
+				if max.is_none() {
+					max = Some(old_min);
+				}
+			} else if v >= *max.as_ref().unwrap_or(&min) {
+				max = Some(v);
+			}
+		}
+		Some(match max {
+			Some(max) => (min, max),
+			None => (min.clone(), min),
+		})
+	}
+
+	/// Finds the first minimum and last maximum by the key
+	///
+	/// Like `min_max`, only clones the element if there is just one.
+	pub fn min_max_by_key<I, K, F>(it :I, mut f :F) -> Option<(I::Item, I::Item)>
+	where
+		I :IntoIterator,
+		I::Item :Clone,
+		K :PartialOrd,
+		F :FnMut(&I::Item) -> K,
+	{
+		let mut it = it.into_iter();
+		let first = it.next()?;
+		let mut min = (f(&first), first);
+		// None while the maximum is the first element, stored in min
+		let mut max :Option<(K, I::Item)> = None;
+		for v in it {
+			let key = f(&v);
+			if key < min.0 {
+				let old_min = std::mem::replace(&mut min, (key, v));
+				if max.is_none() {
+					max = Some(old_min);
+				}
+			} else if key >= max.as_ref().unwrap_or(&min).0 {
+				max = Some((key, v));
+			}
+		}
+		Some(match max {
+			Some(max) => (min.1, max.1),
+			None => (min.1.clone(), min.1),
+		})
+	}
+
+	/// Empty function marked as cold, so that
+	/// paths that call it are considered unlikely
+	#[cold]
+	#[inline]
+	pub fn cold() {}
+
+	#[cfg(feature = "log")]
+	pub use log;
+
+	#[cfg(feature = "tracing")]
+	pub use tracing;
+
+	#[cfg(feature = "anyhow")]
+	pub use anyhow;
+
+	#[cfg(feature = "eyre")]
+	pub use eyre;
+
+	#[cfg(feature = "wasm")]
+	pub use wasm_bindgen;
+	#[cfg(feature = "wasm")]
+	pub use web_sys;
+
+	#[cfg(feature = "metrics")]
+	pub use metrics;
+
+	#[cfg(feature = "serde_json")]
+	pub use serde_json;
+
+	#[cfg(feature = "yaml")]
+	pub use serde_yaml;
+
+	#[cfg(feature = "toml")]
+	pub use toml;
+
+	#[cfg(feature = "ron")]
+	pub use ron;
+
+	#[cfg(feature = "base64")]
+	pub use base64;
+
+	#[cfg(feature = "digest")]
+	pub use digest;
+
+	#[cfg(feature = "tokio")]
+	pub use tokio;
+
+	#[cfg(feature = "futures")]
+	pub use futures;
+
+	#[cfg(feature = "async-std")]
+	pub use async_std;
+
+	#[cfg(feature = "tokio-util")]
+	pub use tokio_util;
+
+	#[cfg(feature = "insta")]
+	pub use insta;
+
+	#[cfg(feature = "proptest")]
+	pub use proptest;
+
+	#[cfg(feature = "pretty_assertions")]
+	pub use pretty_assertions;
+
+	#[cfg(feature = "approx")]
+	pub use approx;
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "anyhow")]
+
+use postfix_macros::{context, postfix_macros, with_context};
+
+postfix_macros! {
+    #[test]
+    fn anyhow_context() {
+        let res :Result<u32, std::num::ParseIntError> = "x".parse();
+        let err = res.context!("parsing {:?}", "x").unwrap_err();
+        assert_eq!(err.to_string(), "parsing \"x\"");
+        assert_eq!(err.root_cause().to_string(), "invalid digit found in string");
+
+        let v :Option<u32> = None;
+        let err = v.context!("missing").unwrap_err();
+        assert_eq!(err.to_string(), "missing");
+
+        assert_eq!(Some(42).context!("missing").unwrap(), 42);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn anyhow_with_context() {
+        let mut evaluated = false;
+        let v = Some(42).with_context!({ evaluated = true; "missing" }).unwrap();
+        assert_eq!(v, 42);
+        assert!(!evaluated);
+
+        let res :Result<(), std::fmt::Error> = Err(std::fmt::Error);
+        let err = res.with_context!(format!("{} failed", "writing")).unwrap_err();
+        assert_eq!(err.to_string(), "writing failed");
+    }
+}
@@ -0,0 +1,15 @@
+#![cfg(feature = "approx")]
+
+use postfix_macros::{approx_eq, postfix_macros};
+
+postfix_macros! {
+    #[test]
+    fn approx_approx_eq() {
+        let measured = 0.1 + 0.2;
+        assert!(measured.approx_eq!(0.3));
+        assert!(measured.approx_eq!(0.3, epsilon = 1e-6));
+        assert!(!measured.approx_eq!(0.3001, epsilon = 1e-6,));
+        assert!(100.0f32.approx_eq!(100.01, max_relative = 1e-3));
+        assert!(!100.0f32.approx_eq!(101.0, epsilon = 1e-3, max_relative = 1e-3));
+    }
+}
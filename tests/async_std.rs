@@ -0,0 +1,18 @@
+#![cfg(all(feature = "async-std", not(feature = "tokio")))]
+
+use postfix_macros::{postfix_macros, spawn_named, spawn_task};
+
+postfix_macros! {
+    #[test]
+    fn async_std_spawn_task() {
+        async fn double(v :u32) -> u32 { v * 2 }
+        let handle = double(21).spawn_task!();
+        assert_eq!(async_std::task::block_on(handle), 42);
+
+        async fn task_name() -> Option<String> {
+            async_std::task::current().name().map(String::from)
+        }
+        let handle = task_name().spawn_named!("worker");
+        assert_eq!(async_std::task::block_on(handle).as_deref(), Some("worker"));
+    }
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "base64")]
+
+use postfix_macros::{base64_dec, base64_enc, postfix_macros};
+
+postfix_macros! {
+    #[test]
+    fn base64_round_trip() {
+        let token = String::from("user:secret");
+        let encoded = token.base64_enc!();
+        assert_eq!(encoded, "dXNlcjpzZWNyZXQ=");
+        assert_eq!(encoded.base64_dec!().unwrap(), token.as_bytes());
+
+        assert_eq!(b"\xfb\xff".base64_enc!(URL_SAFE), "-_8=");
+        assert_eq!("-_8".base64_dec!(URL_SAFE_NO_PAD,).unwrap(), [0xfb, 0xff]);
+    }
+
+    #[test]
+    fn base64_custom_engine() {
+        use base64::engine::general_purpose::{GeneralPurpose, NO_PAD};
+        let engine = GeneralPurpose::new(&base64::alphabet::BCRYPT, NO_PAD);
+        let encoded = "hi".base64_enc!(engine = engine);
+        assert_eq!(encoded.base64_dec!(engine = engine).unwrap(), b"hi");
+        assert!("aGk".base64_dec!().is_err());
+    }
+}
@@ -1,4 +1,132 @@
-use postfix_macros::{match_or, postfix_macros, then, then_else, unwrap_or};
+#![allow(unused_parens)]
+
+use postfix_macros::{
+    ErrorLocation,
+    all_matches,
+    also,
+    any_matches,
+    apply,
+    arc,
+    arc_mutex,
+    assert_impl,
+    assert_type,
+    backtrace_here,
+    bin,
+    bits_dbg,
+    black_box,
+    box_pin,
+    boxed,
+    boxed_dyn,
+    boxed_future,
+    call_counts,
+    catch_unwind,
+    cell,
+    checked_as,
+    clamp_to,
+    cold_path,
+    collect_map,
+    collect_set,
+    collect_string,
+    collect_vec,
+    const_assert,
+    count_calls,
+    count_matches,
+    cow_borrowed,
+    cow_owned,
+    dbg_each,
+    dbg_if,
+    dbg_release,
+    dbgf,
+    defer,
+    elapsed_dbg,
+    eprintln_as,
+    err_wrap,
+    exit_if_err,
+    filter_matches,
+    find_map_matches,
+    find_matches,
+    first_or,
+    first_or_ret,
+    fmt_debug,
+    fmt_debug_pretty,
+    fmt_display,
+    hex,
+    hexdump,
+    if_let,
+    insert_into,
+    inspect_err_dbg,
+    inspect_ok_dbg,
+    into_cow,
+    into_t,
+    is_between,
+    join_str,
+    last_or,
+    last_or_ret,
+    lazy_static,
+    leak_static,
+    leak_static_mut,
+    likely,
+    match_or,
+    memoize,
+    min_max,
+    min_max_by_key,
+    mutex,
+    nonzero,
+    nonzero_opt,
+    nonzero_or_ret,
+    oct,
+    ok_wrap,
+    once,
+    once_cell,
+    parse_as,
+    parse_or,
+    pin,
+    pipe,
+    position_matches,
+    postfix_macros,
+    pretty_bytes,
+    pretty_dbg,
+    println_as,
+    product_as,
+    push_clone_to,
+    push_to,
+    r#match,
+    rc,
+    refcell,
+    repeat_n,
+    replace_with,
+    report_err,
+    retry,
+    rwlock,
+    saturating_as,
+    set_postfix_error_reporter,
+    size_of_val_dbg,
+    some,
+    static_assert_align,
+    static_assert_size,
+    sum_as,
+    swap_with,
+    take_mem,
+    tap,
+    then,
+    then_else,
+    then_some,
+    throttle_log,
+    time,
+    timeit_n,
+    try_into_or,
+    try_into_or_ret,
+    type_name,
+    type_name_of,
+    unlikely,
+    unwrap_or,
+    utf8_lossy,
+    utf8_or,
+    when,
+    with,
+    wrapping_as,
+    write_to,
+};
 
 postfix_macros! {
     #[test]
@@ -42,6 +170,24 @@ postfix_macros! {
 
         false.then!(check = false);
         assert!(check);
+
+        let mut count = 0;
+        for i in 0..4 {
+            // Usable as a statement without a trailing `;`
+            (i % 2 == 0).then!{ count += 1 }
+        }
+        assert_eq!(count, 2);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_then_some() {
+        let mut check = true;
+        assert_eq!(true.then_some!(42), Some(42));
+        assert_eq!(false.then_some!(42), None);
+        assert_eq!(true.then_some!{ check = false; 42 }, Some(42));
+        assert!(!check);
     }
 }
 
@@ -63,3 +209,1147 @@ postfix_macros! {
         assert!(check_else);
     }
 }
+
+postfix_macros! {
+    #[test]
+    fn builtin_exit_if_err() {
+        let res :Result<u32, String> = Ok(42);
+        let v = res.exit_if_err!(3);
+        assert_eq!(v, 42);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_inspect_dbg() {
+        let ok :Result<u32, ()> = Ok(42);
+        assert_eq!(ok.inspect_err_dbg!(), Ok(42));
+        assert_eq!(ok.inspect_ok_dbg!(), Ok(42));
+
+        let err :Result<(), u32> = Err(42);
+        assert_eq!(err.inspect_err_dbg!(), Err(42));
+        assert_eq!(err.inspect_ok_dbg!(), Err(42));
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_catch_unwind() {
+        let res = 42.catch_unwind!();
+        assert_eq!(res.ok(), Some(42));
+
+        let res = panic!("expected panic").catch_unwind!();
+        let res :Result<(), _> = res;
+        assert_eq!(res.unwrap_err().downcast_ref::<&str>(), Some(&"expected panic"));
+
+        let mut check = false;
+        let res = (check = true).catch_unwind!(AssertUnwindSafe);
+        assert!(res.is_ok());
+        assert!(check);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_if_let() {
+        let v = Some(42).if_let!(Some(x) => x + 1);
+        assert_eq!(v, Some(43));
+
+        let v = Some(42).if_let!(Some(x) => x + 1, else => 0);
+        assert_eq!(v, 43);
+
+        let v = (None as Option<u32>).if_let!(Some(x) => x + 1, else => 0);
+        assert_eq!(v, 0);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_match() {
+        let v = Some(42).match! {
+            Some(x) if x > 40 => x - 40,
+            Some(x) => x,
+            None => 0,
+        };
+        assert_eq!(v, 2);
+
+        let s = (4 + 2).match!(6 => "six", _ => "other").to_string();
+        assert_eq!(s, "six");
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_when() {
+        assert_eq!(20.when!(true, |v| v + 1), 21);
+        assert_eq!(20.when!(false, |v| v + 1), 20);
+
+        let add = Some(2);
+        assert_eq!(20.when!(let Some(a) = add, |v| v + a), 22);
+        let add :Option<i32> = None;
+        assert_eq!(20.when!(let Some(a) = add, |v| v + a), 20);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_tap() {
+        let mut seen = Vec::new();
+
+        let v = 42.tap!(|v| seen.push(*v));
+        assert_eq!(v, 42);
+        let v = 43.tap!(move |v| assert_eq!(*v, 43));
+        assert_eq!(v, 43);
+        let v = 44.tap!{ seen.push(*it); seen.push(*it + 1) };
+        assert_eq!(v, 44);
+
+        assert_eq!(seen, [42, 44, 45]);
+
+        use std::cell::Cell;
+        use std::sync::atomic::{AtomicI32, Ordering};
+        static TAPPED :AtomicI32 = AtomicI32::new(0);
+        fn record(v :&i32) {
+            TAPPED.store(*v, Ordering::Relaxed);
+        }
+        let v = 5.tap!(record);
+        assert_eq!(v, 5);
+        assert_eq!(TAPPED.load(Ordering::Relaxed), 5);
+
+        let count = Cell::new(0);
+        let bump = |v :&i32| count.set(count.get() + *v);
+        let v = 6.tap!(bump).tap!(bump,);
+        assert_eq!(v, 6);
+        assert_eq!(count.get(), 12);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_pipe() {
+        fn double(v :u32) -> u32 {
+            v * 2
+        }
+        assert_eq!(21.pipe!(double), 42);
+        assert_eq!(20.pipe!(|v| v + 1).pipe!(double), 42);
+        assert_eq!("42".pipe!(str::parse::<u8>), Ok(42));
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_also() {
+        let s = String::from("hello").also!{ it.push(' '); it.push_str("world") };
+        assert_eq!(s, "hello world");
+
+        let s = s.also!(s => { s.truncate(5); s.make_ascii_uppercase(); });
+        assert_eq!(s, "HELLO");
+
+        let v = 41.also!{ *it += 1 };
+        assert_eq!(v, 42);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_with() {
+        let v = 21.with!(v => v * 2);
+        assert_eq!(v, 42);
+
+        let s = String::from("hello").with!(mut s => {
+            s.push_str(" world");
+            s.len()
+        });
+        assert_eq!(s, 11);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_apply() {
+        let s = String::new().apply!(s => {
+            s.push_str("hello");
+            s.push('!');
+        });
+        assert_eq!(s, "hello!");
+
+        let v = vec![1].apply!(v => v = vec![42]);
+        assert_eq!(v, [42]);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_defer() {
+        let log = std::cell::RefCell::new(Vec::new());
+        {
+            let name = "first";
+            name.defer!(log.borrow_mut().push(name.len()));
+            "second".to_string().defer!(log.borrow_mut().push(it.len()));
+            log.borrow_mut().push(0);
+        }
+        // Dropped in reverse order of declaration
+        assert_eq!(*log.borrow(), [0, 6, 5]);
+
+        log.borrow_mut().clear();
+        {
+            let path = String::from("/tmp/lock");
+            path.defer!(log.borrow_mut().push(path.len()));
+            // The variable is still usable after the defer
+            log.borrow_mut().push(path.len() + 1);
+            assert_eq!(path, "/tmp/lock");
+        }
+        assert_eq!(*log.borrow(), [10, 9]);
+
+        struct Lock<'a>(&'a std::cell::RefCell<Vec<usize>>);
+        impl Lock<'_> {
+            fn hold(&self) {
+                self.defer!(it.0.borrow_mut().push(1));
+                self.0.borrow_mut().push(0);
+            }
+            fn hold_owned(self) {
+                self.defer!(it.0.borrow_mut().push(3));
+                self.0.borrow_mut().push(2);
+            }
+        }
+        log.borrow_mut().clear();
+        Lock(&log).hold();
+        Lock(&log).hold_owned();
+        assert_eq!(*log.borrow(), [0, 1, 2, 3]);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_retry() {
+        let mut calls = 0;
+        let mut fail = || -> Result<(), u32> {
+            calls += 1;
+            Err(calls)
+        };
+        let res = fail().retry!(3);
+        assert_eq!(res, Err(3));
+
+        calls = 0;
+        let res = (|| {
+            calls += 1;
+            if calls == 2 { Ok(calls) } else { Err(()) }
+        }).retry!(5, backoff = std::time::Duration::from_millis(1));
+        assert_eq!(res, Ok(2));
+        assert_eq!(calls, 2);
+
+        calls = 0;
+        let mut flaky = || {
+            calls += 1;
+            if calls == 3 { Ok(calls) } else { Err(calls) }
+        };
+        assert_eq!(flaky.retry!(call, 2), Err(2));
+        let res = flaky.retry!(call, 3, backoff = std::time::Duration::from_millis(1));
+        assert_eq!(res, Ok(3));
+
+        let always_ok = || Ok::<_, ()>(1);
+        assert_eq!(always_ok.retry!(call, 2), Ok(1));
+        fn fetch() -> Result<u32, ()> { Ok(7) }
+        assert_eq!(fetch.retry!(call, 2), Ok(7));
+
+        // A local `std` module doesn't shadow the sleep of the expansion
+        fn with_local_std() -> Result<u32, ()> {
+            #[allow(dead_code)]
+            mod std {}
+            Err(()).retry!(2, backoff = ::std::time::Duration::from_millis(1))
+        }
+        assert_eq!(with_local_std(), Err(()));
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_memoize() {
+        let mut calls = 0;
+        let mut compute = |s :&str| {
+            calls += 1;
+            s.len()
+        };
+        for s in ["a", "bb", "a", "bb", "ccc"].iter() {
+            let v = compute(s).memoize!(s.to_string());
+            assert_eq!(v, s.len());
+        }
+        assert_eq!(calls, 3);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_once() {
+        let mut calls = Vec::new();
+        for i in 0..3 {
+            let v = i.once!();
+            calls.push(v);
+        }
+        assert_eq!(calls, [Some(0), None, None]);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_lazy_static() {
+        let mut calls = 0;
+        let mut compute = |i :u32| {
+            calls += 1;
+            i + 40
+        };
+        let mut refs = Vec::new();
+        for i in 0..3 {
+            let v :&'static u32 = compute(i).lazy_static!(VALUE: u32);
+            refs.push(v);
+        }
+        assert_eq!(calls, 1);
+        assert_eq!(refs, [&40, &40, &40]);
+
+        let v :&'static Vec<u8> = vec![1, 2].once_cell!(Vec<u8>);
+        assert_eq!(*v, [1, 2]);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_leak_static() {
+        let s :&'static String = "hello".to_string().leak_static!();
+        assert_eq!(s, "hello");
+
+        let v :&'static mut u32 = 41.leak_static_mut!();
+        *v += 1;
+        assert_eq!(*v, 42);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_dbg_release() {
+        let v = 40u8.checked_add(2).dbg_release!();
+        assert_eq!(v, Some(42));
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_dbgf() {
+        let v :Vec<u32> = (1..5).map(|v| v * 2).collect().dbgf!("doubled: {:?}");
+        assert_eq!(v, [2, 4, 6, 8]);
+
+        let v = 0.5f32.dbgf!("half = {:.3}");
+        assert_eq!(v, 0.5);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_pretty_dbg() {
+        let v = Some((1, "hello")).pretty_dbg!();
+        assert_eq!(v, Some((1, "hello")));
+
+        let v = vec![0u8; 100].pretty_dbg!(20);
+        assert_eq!(v.len(), 100);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_dbg_if() {
+        let s = String::from("hello");
+        let s = s.dbg_if!(s.len() > 3);
+        assert_eq!(s, "hello");
+
+        let v = (20 + 22).dbg_if!(*it == 42);
+        assert_eq!(v, 42);
+        let v = (20 + 21).dbg_if!(*it == 42);
+        assert_eq!(v, 41);
+
+        #[derive(Debug, PartialEq)]
+        struct Packet(usize);
+        impl Packet {
+            fn checked(&self) -> &Self {
+                self.dbg_if!(it.0 > 1400)
+            }
+            fn into_checked(self) -> Self {
+                self.dbg_if!(it.0 > 1400)
+            }
+        }
+        assert_eq!(Packet(1500).checked(), &Packet(1500));
+        assert_eq!(Packet(64).into_checked(), Packet(64));
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_dbg_each() {
+        let v :Vec<u32> = (1..4).dbg_each!().map(|v| v * 2).dbg_each!().collect();
+        assert_eq!(v, [2, 4, 6]);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_hex_bin_oct() {
+        assert_eq!(255u8.hex!(), 255);
+        assert_eq!((1u32 << 5).bin!(), 32);
+        assert_eq!(0o755.oct!(), 493);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_bits_dbg() {
+        assert_eq!(0b1_1010u8.bits_dbg!(), 26);
+        assert_eq!((-1i16).bits_dbg!(), -1);
+        assert_eq!(0u64.bits_dbg!(), 0);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_hexdump() {
+        let s = "Hello, postfix macros!".hexdump!();
+        assert_eq!(s, "Hello, postfix macros!");
+
+        let v = [0xffu8; 40].hexdump!(20);
+        assert_eq!(v.len(), 40);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_type_name() {
+        let v = Some(42u16).type_name!();
+        assert_eq!(v, Some(42));
+
+        let s = String::from("hi");
+        assert!(s.type_name_of!().ends_with("String"));
+        assert_eq!("hi".type_name_of!(), "&str");
+        assert_eq!(s, "hi");
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_size_of_val_dbg() {
+        let v = [0u64; 4].size_of_val_dbg!();
+        assert_eq!(v.len(), 4);
+        let s = String::from("hello").size_of_val_dbg!();
+        assert_eq!(s, "hello");
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_fmt() {
+        assert_eq!(42.fmt_display!(), "42");
+        assert_eq!("hi".fmt_display!().len(), 2);
+        assert_eq!("hi".fmt_debug!(), "\"hi\"");
+        assert_eq!([1].fmt_debug_pretty!(), "[\n    1,\n]");
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_println_as() {
+        let v = (6 * 7).println_as!("v = {}");
+        assert_eq!(v, 42);
+        let v = "hi".to_string().eprintln_as!("{} {}", "there");
+        assert_eq!(v, "hi");
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_write_to() {
+        use std::fmt::Write;
+
+        let mut s = String::new();
+        42.write_to!(s, "{}-{}", 43).unwrap();
+        s.write_to!(" and {}", "more").unwrap();
+        assert_eq!(s, "42-43 and more");
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_backtrace_here() {
+        use std::error::Error;
+
+        let res :Result<u8, std::num::ParseIntError> = "x".parse();
+        let err = res.backtrace_here!().unwrap_err();
+        assert!(err.to_string().starts_with("invalid digit found in string\n\nBacktrace:\n"));
+        assert!(err.source().is_some());
+
+        let res :Result<u8, std::num::ParseIntError> = Ok(42);
+        assert_eq!(res.backtrace_here!().unwrap(), 42);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_report_err() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        static REPORTED :AtomicUsize = AtomicUsize::new(0);
+        fn reporter(err :&dyn std::fmt::Debug, loc :&ErrorLocation) {
+            assert_eq!(format!("{:?}", err), "\"boom\"");
+            assert!(loc.file.ends_with("builtin.rs"));
+            REPORTED.fetch_add(1, Ordering::SeqCst);
+        }
+        assert!(set_postfix_error_reporter!(reporter));
+        assert!(!set_postfix_error_reporter!(|_, _| panic!()));
+
+        let ok :Result<u8, &str> = Ok(1);
+        assert_eq!(ok.report_err!(), Ok(1));
+        assert_eq!(REPORTED.load(Ordering::SeqCst), 0);
+
+        let err :Result<u8, &str> = Err("boom");
+        assert_eq!(err.report_err!(), Err("boom"));
+        assert_eq!(REPORTED.load(Ordering::SeqCst), 1);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_pretty_bytes() {
+        assert_eq!(0u8.pretty_bytes!(), "0 B");
+        assert_eq!(1023usize.pretty_bytes!(), "1023 B");
+        assert_eq!(1024u16.pretty_bytes!(binary), "1.0 KiB");
+        assert_eq!(1536u32.pretty_bytes!(), "1.5 KiB");
+        assert_eq!(999u32.pretty_bytes!(decimal), "999 B");
+        assert_eq!(1_000_000u64.pretty_bytes!(decimal), "1.0 MB");
+        assert_eq!(u64::MAX.pretty_bytes!(), "16.0 EiB");
+        assert_eq!(u64::MAX.pretty_bytes!(decimal), "18.4 EB");
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_smart_pointers() {
+        let b = 5.boxed!();
+        assert_eq!(*b + 1, 6);
+
+        let shapes = vec![
+            1u8.boxed_dyn!(std::fmt::Debug),
+            "two".boxed_dyn!(std::fmt::Debug),
+        ];
+        assert_eq!(format!("{:?}", shapes), "[1, \"two\"]");
+
+        let f = (|s: &str| s.len()).boxed_dyn!(Fn(&str) -> usize + Send + Sync);
+        assert_eq!(f("abc"), 3);
+
+        let r = "shared".rc!();
+        assert_eq!(std::rc::Rc::strong_count(&r.clone()), 2);
+
+        let a = vec![1].arc!();
+        assert_eq!(std::sync::Arc::strong_count(&a), 1);
+        assert_eq!(*a, [1]);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_interior_mutability() {
+        let c = 'a'.cell!();
+        c.set('b');
+        assert_eq!(c.get(), 'b');
+
+        let r = String::new().refcell!();
+        r.borrow_mut().push_str("hi");
+        assert_eq!(r.into_inner(), "hi");
+
+        let m = 1.mutex!();
+        *m.lock().unwrap() += 1;
+        assert_eq!(m.into_inner().unwrap(), 2);
+
+        let l = vec![0u8; 2].rwlock!();
+        l.write().unwrap()[1] = 7;
+        assert_eq!(*l.read().unwrap(), [0, 7]);
+
+        let shared = Some(3).arc_mutex!();
+        let other = std::sync::Arc::clone(&shared);
+        *other.lock().unwrap() = None;
+        assert_eq!(*shared.lock().unwrap(), None);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_pin() {
+        let mut pinned = String::from("a").pin!();
+        pinned.as_mut().get_mut().push('b');
+        assert_eq!(*pinned, "ab");
+
+        let boxed :std::pin::Pin<Box<u8>> = 1.box_pin!();
+        assert_eq!(*boxed, 1);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_cow() {
+        use std::borrow::Cow;
+
+        let slice = [1, 2, 3];
+        let borrowed :Cow<[i32]> = (&slice[..]).cow_borrowed!();
+        assert!(matches!(borrowed, Cow::Borrowed(_)));
+
+        let owned = String::from("x").cow_owned!(str);
+        assert!(matches!(owned, Cow::Owned(_)));
+        let inferred :Cow<str> = String::from("y").cow_owned!();
+        assert_eq!(inferred, "y");
+
+        let from_vec = vec![1u8].into_cow!();
+        assert!(matches!(from_vec, Cow::Owned(_)));
+        let from_str = "z".into_cow!();
+        assert!(matches!(from_str, Cow::Borrowed("z")));
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_variant_wrappers() {
+        assert_eq!("a".to_string().some!(), Some("a".to_string()));
+        let ok :Result<u8, ()> = 1.ok_wrap!();
+        assert_eq!(ok, Ok(1));
+        let err :Result<(), &str> = "bad".err_wrap!();
+        assert_eq!(err, Err("bad"));
+        assert_eq!(vec![1, 2].len().some!().some!(), Some(Some(2)));
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_into_t() {
+        assert_eq!(7u8.into_t!(i32) - 10, -3);
+        let v = [1, 2].into_t!(Vec<i32>);
+        assert_eq!(v, vec![1, 2]);
+        let o = 'x'.into_t!(Option<char>,);
+        assert_eq!(o, Some('x'));
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_try_into_or() {
+        assert_eq!((-1i32).try_into_or!(u32, 0), 0);
+        assert_eq!(5i64.try_into_or!(usize, unreachable!()), 5);
+
+        let mut small = Vec::new();
+        for v in [1u64, 1 << 40, 3].iter().copied() {
+            small.push(v.try_into_or!(u32, continue));
+        }
+        assert_eq!(small, [1, 3]);
+
+        fn halve(v :i128) -> Result<i8, &'static str> {
+            let v = v.try_into_or_ret!(i8, Err("out of range"));
+            Ok(v / 2)
+        }
+        assert_eq!(halve(-100), Ok(-50));
+        assert_eq!(halve(1000), Err("out of range"));
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_parse_as() {
+        assert_eq!("-3".parse_as!(i8), Ok(-3));
+        let owned = String::from("true");
+        assert_eq!(owned.parse_as!(bool), Ok(true));
+        assert_eq!(owned.len(), 4);
+        let addr = "127.0.0.1".parse_as!(std::net::Ipv4Addr).map(|a| a.is_loopback());
+        assert_eq!(addr, Ok(true));
+        assert!("256".parse_as!(u8,).is_err());
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_parse_or() {
+        assert_eq!("12".parse_or!(0u8), 12);
+        assert_eq!("-1".parse_or!(0u8), 0);
+        let val = String::from("2.5");
+        assert_eq!(val.parse_or!(1.0), 2.5);
+        assert!("".parse_or!(true, "parsing flag"));
+        let timeout :u64 = "soon".parse_or!(30);
+        assert_eq!(timeout, 30);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_nonzero() {
+        use std::num::{NonZeroI64, NonZeroU8};
+
+        let v :NonZeroU8 = 3u8.nonzero!();
+        assert_eq!(v.get(), 3);
+        let w :NonZeroI64 = (-5i64).nonzero!();
+        assert_eq!(w.get(), -5);
+        assert_eq!(7u32.nonzero_opt!().map(|v| v.get()), Some(7));
+        assert!(0usize.nonzero_opt!().is_none());
+
+        fn first_nonzero(vals :&[u16]) -> u16 {
+            for &v in vals {
+                let v = v.nonzero_or_ret!(0);
+                if v.get() > 1 {
+                    return v.get();
+                }
+            }
+            1
+        }
+        assert_eq!(first_nonzero(&[1, 2]), 2);
+        assert_eq!(first_nonzero(&[1, 0, 2]), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "zero")]
+    fn builtin_nonzero_panics() {
+        let empty :Vec<u8> = Vec::new();
+        empty.len().nonzero!();
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_int_casts() {
+        assert_eq!(1000i32.saturating_as!(i8), 127);
+        assert_eq!((-1000i32).saturating_as!(i8), -128);
+        assert_eq!((-1i8).saturating_as!(u128), 0);
+        assert_eq!(u128::MAX.saturating_as!(i64), i64::MAX);
+        assert_eq!(5usize.saturating_as!(u8), 5);
+
+        assert_eq!(256u16.wrapping_as!(u8), 0);
+        assert_eq!(255u8.wrapping_as!(i8), -1);
+        assert_eq!((-2i64).wrapping_as!(u16), 65534);
+
+        assert_eq!(256u16.checked_as!(u8), None);
+        assert_eq!(255u16.checked_as!(u8), Some(255));
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_utf8() {
+        let valid = "grüße".as_bytes();
+        assert_eq!(valid.utf8_or!(""), "grüße");
+        let invalid = vec![b'a', 0x80];
+        assert_eq!(invalid.utf8_or!("?"), "?");
+
+        let mut lines = Vec::new();
+        let bufs :[&[u8]; 3] = [b"one", &[0xc3], b"two"];
+        for buf in bufs.iter() {
+            lines.push(buf.utf8_or!(continue).to_string());
+        }
+        assert_eq!(lines, ["one", "two"]);
+
+        assert_eq!(invalid.utf8_lossy!(), "a\u{FFFD}");
+        assert!(matches!(valid.utf8_lossy!(), std::borrow::Cow::Borrowed(_)));
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_clamp_to() {
+        assert_eq!(5.clamp_to!(1..=3), 3);
+        assert_eq!(0.clamp_to!(1..=3), 1);
+        assert_eq!(2.clamp_to!(1..=3), 2);
+        assert_eq!((-0.5f64).clamp_to!(0.0..), 0.0);
+        assert_eq!(1e9.clamp_to!(..=1e3), 1e3);
+        let (lo, hi) = ("b".to_string(), "d".to_string());
+        assert_eq!("z".to_string().clamp_to!(lo..=hi), "d");
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_is_between() {
+        assert!(5.is_between!(0..10));
+        assert!(!10.is_between!(0..10));
+        assert!(10.is_between!(0..=10));
+        assert!(0.5.is_between!(..1.0));
+        assert!('q'.is_between!('a'..='z'));
+        let (lo, hi) = (3, 7);
+        assert!(!(lo + hi).is_between!(lo..hi));
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_assert_type() {
+        let n = "5".parse().unwrap().assert_type!(u16);
+        assert_eq!(n, 5);
+        let s :&[u8] = (&[1u8, 2][..]).assert_type!(&[u8]);
+        assert_eq!(s.len(), 2);
+        let b = Box::new(1).assert_type!(Box<dyn std::fmt::Debug>);
+        assert_eq!(format!("{:?}", b), "1");
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_assert_impl() {
+        let v = vec![1].assert_impl!(Clone + std::fmt::Debug);
+        assert_eq!(v.len(), 1);
+        let s = "static".assert_impl!(Copy + 'static);
+        assert_eq!(s, "static");
+        let f = (|x :u8| x + 1).assert_impl!(Fn(u8) -> u8 + Send);
+        assert_eq!(f(1), 2);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_static_assert_layout() {
+        let v = [0u16; 4].static_assert_size!(8).static_assert_align!(2);
+        assert_eq!(v.len(), 4);
+        let t = (1u8, 2u32).static_assert_size!(2 * 4);
+        assert_eq!(t.1, 2);
+        let p :std::marker::PhantomData<u64> = std::marker::PhantomData;
+        let p = p.static_assert_size!(0).static_assert_align!(1,);
+        assert_eq!(p, std::marker::PhantomData);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_const_assert() {
+        const PAGE :u32 = 4096;
+        assert_eq!(PAGE.const_assert!(is_power_of_two()), 4096);
+        assert_eq!(PAGE.const_assert!(>= 1024).const_assert!(< 1 << 16), 4096);
+        assert_eq!(3u8.const_assert!(checked_add(1).is_some()), 3);
+        let name = "app".const_assert!(len() == 3);
+        assert_eq!(name, "app");
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_collect() {
+        let v = (1..4).map(|x| x * x).collect_vec!();
+        assert_eq!(v, [1, 4, 9]);
+        let set = v.iter().map(|x| x % 2).collect_set!();
+        assert_eq!(set.len(), 2);
+        let map = v.iter().enumerate().collect_map!();
+        assert_eq!(map[&2], &9);
+        let s = vec!["a", "b"].into_iter().collect_string!();
+        assert_eq!(s, "ab");
+        let parsed = "1 2".split(' ').map(|s| s.parse::<u8>()).collect_vec!();
+        assert_eq!(parsed, [Ok(1), Ok(2)]);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_filter_matches() {
+        let vals = vec![Some(1), None, Some(5), Some(2)];
+        let big = vals.iter().filter_matches!(Some(v) if *v > 1).collect_vec!();
+        assert_eq!(big, [&Some(5), &Some(2)]);
+        let small = vals.into_iter().filter_matches!(Some(1) | Some(2),).count();
+        assert_eq!(small, 2);
+        let words = "abcd".chars().filter_matches!('a' | 'c').collect_string!();
+        assert_eq!(words, "ac");
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_find_matches() {
+        let vals = vec![Err("a"), Ok(1), Ok(7), Err("b")];
+        assert_eq!(vals.iter().find_matches!(Ok(v) if *v > 1), Some(&Ok(7)));
+        assert_eq!(vals.iter().find_matches!(Ok(0) | Err("c")), None);
+
+        let mut it = vals.iter();
+        assert_eq!(it.by_ref().find_matches!(Err("a") | Err("b")), Some(&Err("a")));
+        assert_eq!(it.find_matches!(Err("a") | Err("b")), Some(&Err("b")));
+
+        assert_eq!(vals.iter().find_map_matches!(Ok(v) => v * 10), Some(10));
+        assert_eq!(vals.iter().find_map_matches!(Err(e) if e.starts_with('b') => e.len()), Some(1));
+        assert_eq!(vals.into_iter().find_map_matches!(Ok(v) if v > 7 => v,), None);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_count_matches() {
+        let text = "a, b; c, d";
+        assert_eq!(text.chars().count_matches!(',' | ';'), 3);
+        assert_eq!(text.chars().count_matches!(c if c.is_alphabetic(),), 4);
+        let empty :Vec<Option<u8>> = Vec::new();
+        assert_eq!(empty.iter().count_matches!(Some(1)), 0);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_position_matches() {
+        assert_eq!("key=value".chars().position_matches!('=' | ':'), Some(3));
+        let words = vec![String::from("a"), String::from("long")];
+        assert_eq!(words.iter().position_matches!(w if w.len() > 2), Some(1));
+        assert_eq!(words.into_iter().position_matches!(w if w.is_empty(),), None);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_any_all_matches() {
+        let digits = "2024";
+        assert!(digits.chars().all_matches!('0'..='9'));
+        assert!(!digits.chars().any_matches!('5' | '6'));
+        assert!(digits.chars().any_matches!(c if c.to_digit(10) == Some(4),));
+
+        let none :Vec<Option<u8>> = Vec::new();
+        assert!(none.iter().all_matches!(Some(1)));
+        assert!(!none.into_iter().any_matches!(None | Some(1)));
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_push_insert() {
+        let mut evens = Vec::new();
+        (0..6).filter(|x| x % 2 == 0).max().push_to!(evens);
+        assert_eq!(evens, [Some(4)]);
+        let mut log = Vec::new();
+        let kept = vec!['c'].push_clone_to!(log);
+        assert_eq!(kept, log[0]);
+
+        let mut map = std::collections::HashMap::new();
+        assert_eq!(1.insert_into!(map, "one"), None);
+        assert_eq!(11.insert_into!(map, "one",), Some(1));
+        let mut set = std::collections::BTreeSet::new();
+        assert!(3.insert_into!(set));
+        assert!(!3.insert_into!(set,));
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_join_str() {
+        let empty :Vec<&str> = Vec::new();
+        assert_eq!(empty.iter().join_str!(","), "");
+        assert_eq!(vec!["x"].into_iter().join_str!(","), "x");
+        let sep = String::from(" | ");
+        assert_eq!((1..4).map(|i| i.to_string()).join_str!(&sep), "1 | 2 | 3");
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_sum_product_as() {
+        let vals = [1.5f64, 2.5];
+        assert_eq!(vals.iter().sum_as!(f64), 4.0);
+        assert_eq!(vals.iter().product_as!(f64), 3.75);
+        let empty :Vec<u8> = Vec::new();
+        assert_eq!(empty.into_iter().sum_as!(u8,).checked_add(1), Some(1));
+        let opt = vec![Some(2), Some(3)].into_iter().product_as!(Option<i32>);
+        assert_eq!(opt, Some(6));
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_mem_helpers() {
+        let mut v = vec![String::from("a"), String::from("b")];
+        assert_eq!(v[0].replace_with!(String::from("c")), "a");
+        assert_eq!(v[1].take_mem!(), "b");
+        assert_eq!(v, ["c", ""]);
+
+        let mut n = 1;
+        assert_eq!(n.replace_with!(2), 1);
+        let mut m = 3;
+        n.swap_with!(m);
+        assert_eq!((n, m), (3, 2));
+
+        let mut opt = Some(4);
+        let r = &mut opt;
+        assert_eq!((*r).take_mem!(), Some(4));
+        assert_eq!(opt, None);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_repeat_n() {
+        let count = 2;
+        assert_eq!(Some(1).repeat_n!(count), [Some(1), Some(1)]);
+        assert!("x".repeat_n!(0).is_empty());
+        assert_eq!(7.repeat_n!(3, iter).sum::<i32>(), 21);
+        const N :usize = 3;
+        let arr = String::from("a").repeat_n!([N]);
+        assert_eq!(arr.len(), 3);
+        assert_eq!(arr[2], "a");
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_first_last_or() {
+        let v :Vec<String> = "a b".split(' ').map(String::from).collect();
+        let empty :Vec<String> = Vec::new();
+        let fallback = String::from("none");
+        assert_eq!(v.first_or!(&fallback), "a");
+        assert_eq!(v.last_or!(&fallback), "b");
+        assert_eq!(empty.first_or!(&fallback), "none");
+        assert_eq!(v[1..].first_or!(unreachable!()), "b");
+
+        let nested = vec![vec![1], vec![]];
+        let mut firsts = Vec::new();
+        for inner in &nested {
+            firsts.push(*inner.first_or!(continue));
+        }
+        assert_eq!(firsts, [1]);
+
+        fn sum_ends(v :&[i32]) -> i32 {
+            v.first_or_ret!(0) + v.last_or_ret!(0)
+        }
+        assert_eq!(sum_ends(&[1, 5, 3]), 4);
+        assert_eq!(sum_ends(&[]), 0);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_min_max() {
+        assert_eq!(vec![4, 2, 9, 2].into_iter().min_max!(), Some((2, 9)));
+        assert_eq!(Some(5).into_iter().min_max!(), Some((5, 5)));
+        assert_eq!(vec![3.0, f64::NAN, 1.0, 3.0].into_iter().min_max!(), Some((1.0, 3.0)));
+
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        static CLONES :AtomicUsize = AtomicUsize::new(0);
+        #[derive(Debug, PartialEq, PartialOrd)]
+        struct Tracked(u32, &'static str);
+        impl Clone for Tracked {
+            fn clone(&self) -> Self {
+                CLONES.fetch_add(1, Ordering::Relaxed);
+                Tracked(self.0, self.1)
+            }
+        }
+        let items = vec![Tracked(2, "a"), Tracked(1, "b"), Tracked(3, "c"), Tracked(1, "d"), Tracked(3, "e")];
+        let (min, max) = items.into_iter().min_max!().unwrap();
+        assert_eq!((min.1, max.1), ("b", "e"));
+        assert_eq!(CLONES.load(Ordering::Relaxed), 0);
+        let (min, max) = vec![Tracked(7, "x")].into_iter().min_max!().unwrap();
+        assert_eq!((min.1, max.1), ("x", "x"));
+        assert_eq!(CLONES.load(Ordering::Relaxed), 1);
+
+        let words = ["bb", "a", "ccc", "dd", "e"];
+        let (short, long) = words.iter().min_max_by_key!(|w| w.len()).unwrap();
+        assert_eq!((*short, *long), ("a", "ccc"));
+        let (first_min, last_max) = words.iter().min_max_by_key!(|w| w.len() % 2).unwrap();
+        assert_eq!((*first_min, *last_max), ("bb", "e"));
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_time() {
+        let sum = (1..=100).sum::<u32>();
+        assert_eq!(sum.time!(), 5050);
+        let mut v = vec![3, 1, 2];
+        v.sort_unstable().time!("sorting");
+        assert_eq!(v, [1, 2, 3]);
+        assert_eq!(v.len().time!(String::from("len"),), 3);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_timeit_n() {
+        let mut calls = 0;
+        (|| calls += 1).timeit_n!(10);
+        assert_eq!(calls, 10);
+        let f = || 1 + 1;
+        f.timeit_n!(0,);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_elapsed_dbg() {
+        let start = std::time::Instant::now();
+        let first = start.elapsed_dbg!();
+        let second = start.elapsed_dbg!("second",);
+        assert!(second >= first);
+        let total = std::time::Instant::now().elapsed_dbg!(format!("run {}", 1)) + first;
+        assert!(total >= first);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_black_box() {
+        assert_eq!(vec![1, 2].black_box!().len().black_box!(), 2);
+        let s = String::from("x");
+        assert_eq!(s.black_box!(), "x");
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_count_calls() {
+        for i in 0..5 {
+            let v = i.count_calls!("builtin_count_calls loop");
+            assert_eq!(v, i);
+            if i > 2 {
+                i.count_calls!("builtin_count_calls branch");
+            }
+        }
+        let counts = call_counts!();
+        assert!(counts.contains(&("builtin_count_calls loop", 5)));
+        assert!(counts.contains(&("builtin_count_calls branch", 2)));
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_throttle_log() {
+        use std::time::Duration;
+        let mut sum = 0;
+        for i in 0..100 {
+            sum += i.throttle_log!(Duration::from_secs(60));
+            let s = "item".throttle_log!(Duration::ZERO, "{}");
+            assert_eq!(s, "item");
+        }
+        assert_eq!(sum, 4950);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_branch_hints() {
+        let mut hits = 0;
+        for i in 0..10 {
+            let small = i < 9;
+            if small.likely!() {
+                hits += 1;
+            }
+            let last = i == 9;
+            if last.unlikely!() {
+                hits += 10;
+            }
+        }
+        assert_eq!(hits, 19);
+        let v = (1 + 1).cold_path!();
+        assert_eq!(v, 2);
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn builtin_boxed_future() {
+        use std::future::Future;
+        use std::pin::Pin;
+        use std::rc::Rc;
+
+        fn assert_send<T :Send>(_ :&T) {}
+
+        async fn double(v :u32) -> u32 { v * 2 }
+        let futures = vec![double(1).boxed_future!(), double(2).boxed_future!()];
+        assert_send(&futures);
+        assert_eq!(futures.len(), 2);
+
+        async fn deref(rc :Rc<u32>) -> u32 { *rc }
+        let fut :Pin<Box<dyn Future<Output = u32>>> = deref(Rc::new(3)).boxed_future!(local);
+        drop(fut);
+    }
+}
@@ -0,0 +1,17 @@
+#![cfg(feature = "digest")]
+
+use postfix_macros::{digest, digest_hex, postfix_macros};
+use sha2::{Sha256, Sha512};
+
+postfix_macros! {
+    #[test]
+    fn digest_hash() {
+        let data = String::from("abc");
+        assert_eq!(data.digest!(Sha256).len(), 32);
+        assert_eq!(data.digest!(Sha512).len(), 64);
+        assert_eq!(data.digest_hex!(Sha256),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        assert_eq!(b"".digest_hex!(sha2::Sha224),
+            "d14a028c2a3a2bc9476102bb288234c415a2b01f828ea62ac5b3e42f");
+    }
+}
@@ -0,0 +1,19 @@
+#![cfg(feature = "eyre")]
+
+use postfix_macros::{postfix_macros, wrap_err, wrap_err_with};
+
+postfix_macros! {
+    #[test]
+    fn eyre_wrap_err() {
+        let res :Result<u32, std::num::ParseIntError> = "x".parse();
+        let err = res.wrap_err!("parsing {:?}", "x").unwrap_err();
+        assert_eq!(err.to_string(), "parsing \"x\"");
+        assert_eq!(err.root_cause().to_string(), "invalid digit found in string");
+
+        let mut evaluated = false;
+        let res :Result<u32, std::fmt::Error> = Ok(42);
+        let v = res.wrap_err_with!{ evaluated = true; "failed" }.unwrap();
+        assert_eq!(v, 42);
+        assert!(!evaluated);
+    }
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "futures")]
+
+use futures::future::{pending, ready, Either};
+use postfix_macros::{block_on, postfix_macros, race_with};
+
+postfix_macros! {
+    #[test]
+    fn futures_block_on() {
+        async fn double(v :u32) -> u32 { v * 2 }
+        assert_eq!(double(21).block_on!(), 42);
+
+        let fut = async { "done" };
+        assert_eq!(fut.block_on!(), "done");
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn futures_race_with() {
+        async fn race_pending() -> Either<u32, &'static str> {
+            let left :futures::future::Pending<u32> = pending();
+            left.race_with!(ready("right"))
+        }
+        assert!(matches!(race_pending().block_on!(), Either::Right("right")));
+
+        async fn race_ready() -> Either<u32, u32> {
+            ready(1).race_with!(ready(2))
+        }
+        assert!(matches!(race_ready().block_on!(), Either::Left(1)));
+    }
+}
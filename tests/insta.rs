@@ -0,0 +1,16 @@
+#![cfg(feature = "insta")]
+
+use postfix_macros::{assert_snapshot, postfix_macros};
+use std::collections::BTreeMap;
+
+postfix_macros! {
+    #[test]
+    fn insta_assert_snapshot() {
+        let mut point = BTreeMap::new();
+        point.insert("x", 1);
+        point.insert("y", 2);
+        format!("({}, {})", point["x"], point["y"]).assert_snapshot!();
+        point.assert_snapshot!(debug, "point_debug");
+        point.assert_snapshot!(json, "point_json");
+    }
+}
@@ -0,0 +1,15 @@
+#![cfg(feature = "log")]
+
+use postfix_macros::{log_debug, log_error, log_info, log_warn, postfix_macros};
+
+postfix_macros! {
+    #[test]
+    fn log_pass_through() {
+        assert_eq!(42.log_debug!(), 42);
+        assert_eq!("hi".log_info!("greeting"), "hi");
+        assert_eq!(Some(1).log_warn!("{} value", "optional"), Some(1));
+
+        let res :Result<(), ()> = Err(());
+        assert_eq!(res.log_error!("failed"), Err(()));
+    }
+}
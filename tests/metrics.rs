@@ -0,0 +1,15 @@
+#![cfg(feature = "metrics")]
+
+use postfix_macros::{count_metric, gauge_metric, histogram_metric, postfix_macros};
+
+postfix_macros! {
+    #[test]
+    fn metrics_pass_through() {
+        assert_eq!("body".count_metric!("requests_total"), "body");
+        assert_eq!(3u32.count_metric!("requests_total", method = "GET", code = 200), 3);
+        assert_eq!(7u32.gauge_metric!("queue_length"), 7);
+
+        let path = String::from("/index");
+        assert_eq!(1.5.histogram_metric!("request_latency", route = path,), 1.5);
+    }
+}
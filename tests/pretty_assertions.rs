@@ -0,0 +1,19 @@
+#![cfg(feature = "pretty_assertions")]
+
+use postfix_macros::{assert_eq_pretty, postfix_macros};
+
+postfix_macros! {
+    #[test]
+    fn pretty_assertions_assert_eq_pretty() {
+        let v = vec![1, 2, 3];
+        let sum :i32 = v.iter().sum();
+        sum.assert_eq_pretty!(6);
+        v.len().assert_eq_pretty!(3, "length of {:?}", v);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: `(left == right)`")]
+    fn pretty_assertions_assert_eq_pretty_panics() {
+        "left".assert_eq_pretty!("right");
+    }
+}
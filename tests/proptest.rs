@@ -0,0 +1,36 @@
+#![cfg(feature = "proptest")]
+
+use postfix_macros::{postfix_macros, prop_assert_matches, prop_assert_ok};
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+
+postfix_macros! {
+    proptest! {
+        #[test]
+        fn proptest_roundtrip(n in 0..1000u32) {
+            let s = n.to_string();
+            let parsed :Result<u32, _> = s.parse();
+            parsed.prop_assert_matches!(Ok(v) if v == n);
+            let parsed :Result<u32, _> = s.parse();
+            prop_assert_eq!(parsed.prop_assert_ok!(), n);
+        }
+    }
+
+    #[test]
+    fn proptest_failures() {
+        fn check_matches(v :Option<u32>) -> Result<(), TestCaseError> {
+            v.prop_assert_matches!(Some(1..=5));
+            Ok(())
+        }
+        assert!(check_matches(Some(3)).is_ok());
+        let err = check_matches(Some(7)).unwrap_err();
+        assert!(err.to_string().contains("`Some(7)` does not match"), "{}", err);
+
+        fn check_ok(v :Result<u32, &str>) -> Result<u32, TestCaseError> {
+            Ok(v.prop_assert_ok!() + 1)
+        }
+        assert_eq!(check_ok(Ok(1)).unwrap(), 2);
+        let err = check_ok(Err("boom")).unwrap_err();
+        assert!(err.to_string().contains("`\"boom\"` is not `Ok`"), "{}", err);
+    }
+}
@@ -0,0 +1,18 @@
+#![cfg(feature = "ron")]
+
+use postfix_macros::{postfix_macros, to_ron, to_ron_pretty, try_to_ron, try_to_ron_pretty};
+use std::collections::BTreeMap;
+
+postfix_macros! {
+    #[test]
+    fn ron_to_ron() {
+        let mut assets = BTreeMap::new();
+        assets.insert("player", (0, 1));
+        assert_eq!(assets.to_ron!(), r#"{"player":(0,1)}"#);
+        assert_eq!(assets.try_to_ron!().unwrap(), r#"{"player":(0,1)}"#);
+
+        let pretty = assets.to_ron_pretty!();
+        assert!(pretty.contains('\n'));
+        assert_eq!(assets.try_to_ron_pretty!().unwrap(), pretty);
+    }
+}
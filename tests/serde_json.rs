@@ -0,0 +1,46 @@
+#![cfg(feature = "serde_json")]
+
+use postfix_macros::{
+    from_json_or, postfix_macros, to_json, to_json_pretty, try_to_json, try_to_json_pretty,
+};
+use std::collections::BTreeMap;
+
+postfix_macros! {
+    #[test]
+    fn serde_json_to_json() {
+        let mut map = BTreeMap::new();
+        map.insert("a", vec![1, 2]);
+        assert_eq!(map.to_json!(), r#"{"a":[1,2]}"#);
+        assert_eq!(map.to_json_pretty!(), "{\n  \"a\": [\n    1,\n    2\n  ]\n}");
+        assert_eq!(map.try_to_json!().unwrap(), r#"{"a":[1,2]}"#);
+        assert_eq!("x".try_to_json_pretty!().unwrap(), "\"x\"");
+    }
+
+    #[test]
+    fn serde_json_try_to_json_err() {
+        let mut map = BTreeMap::new();
+        map.insert((1, 2), 3);
+        assert!(map.try_to_json!().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "failed to serialize to JSON")]
+    fn serde_json_to_json_panics() {
+        let mut map = BTreeMap::new();
+        map.insert((1, 2), 3);
+        map.to_json!();
+    }
+
+    #[test]
+    fn serde_json_from_json_or() {
+        let map :BTreeMap<String, u32> = r#"{"a":1}"#.from_json_or!(BTreeMap::new());
+        assert_eq!(map["a"], 1);
+
+        let bytes :&[u8] = b"[1, 2]";
+        assert_eq!(bytes.from_json_or!(vec![0u8]), [1, 2]);
+
+        let body = String::from("{");
+        assert_eq!(body.from_json_or!(vec![0u8]), [0]);
+        assert_eq!(body.from_json_or!(None, "parsing body"), None::<u8>);
+    }
+}
@@ -88,3 +88,37 @@ postfix_macros! {
 		((), ().no_comma_pattern_macro!());
 	}
 }
+
+mod helpers {
+	macro_rules! double {
+		($v:expr) => {
+			$v * 2
+		};
+	}
+	pub(crate) use double;
+}
+
+postfix_macros! {
+	macro_rules! add_one {
+		($v:expr) => {
+			$v + 1
+		};
+	}
+	pub(crate) use add_one;
+
+	#[test]
+	fn path_qualified() {
+		// Test that macros can be invoked via their paths
+		assert_eq!(20.helpers::double!().self::add_one!(), 41);
+		assert_eq!(20.crate::helpers::double!(), 40);
+		let v = None.postfix_macros::unwrap_or!(42);
+		v.std::assert_eq!(42);
+		// Test that keywords in the last path segment are turned
+		// into raw identifiers
+		let w = Some(3).postfix_macros::match!{
+			Some(v) => v,
+			None => 0,
+		};
+		assert_eq!(w, 3);
+	}
+}
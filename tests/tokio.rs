@@ -0,0 +1,78 @@
+#![cfg(feature = "tokio")]
+
+use postfix_macros::{await_timeout, block_on, postfix_macros, retry_async, spawn_named, spawn_task};
+use std::time::Duration;
+
+postfix_macros! {
+    #[tokio::test]
+    async fn tokio_await_timeout() {
+        async fn ready() -> u32 { 42 }
+        let v = ready().await_timeout!(Duration::from_secs(5));
+        assert_eq!(v, Ok(42));
+
+        let pending = std::future::pending::<u32>();
+        let res = pending.await_timeout!(Duration::from_millis(10));
+        assert!(res.is_err());
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn tokio_block_on() {
+        async fn double(v :u32) -> u32 { v * 2 }
+        let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        assert_eq!(double(21).block_on!(rt), 42);
+        assert_eq!(double(4).block_on!(rt.handle()), 8);
+    }
+}
+
+#[cfg(not(feature = "futures"))]
+postfix_macros! {
+    #[test]
+    fn tokio_block_on_own_runtime() {
+        async fn sleepy(v :u32) -> u32 {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            v
+        }
+        assert_eq!(sleepy(42).block_on!(), 42);
+    }
+}
+
+postfix_macros! {
+    #[tokio::test]
+    async fn tokio_spawn_task() {
+        async fn double(v :u32) -> u32 { v * 2 }
+        let handle = double(21).spawn_task!();
+        assert_eq!(handle.await.unwrap(), 42);
+
+        let handle = double(4).spawn_named!("worker");
+        assert_eq!(handle.await.unwrap(), 8);
+    }
+}
+
+postfix_macros! {
+    #[tokio::test]
+    async fn tokio_retry_async() {
+        use std::cell::Cell;
+
+        async fn fetch(calls :&Cell<u32>) -> Result<u32, u32> {
+            calls.set(calls.get() + 1);
+            if calls.get() < 3 { Err(calls.get()) } else { Ok(calls.get()) }
+        }
+
+        let calls = Cell::new(0);
+        assert_eq!(fetch(&calls).retry_async!(2), Err(2));
+
+        let calls = Cell::new(0);
+        let res = (|| fetch(&calls)).retry_async!(3, backoff = Duration::from_millis(1));
+        assert_eq!(res, Ok(3));
+
+        let calls = &Cell::new(0);
+        let res = (move || fetch(calls)).retry_async!(5, backoff = exp(Duration::from_millis(1)));
+        assert_eq!(res, Ok(3));
+
+        let calls = Cell::new(0);
+        let fetch_calls = || fetch(&calls);
+        assert_eq!(fetch_calls.retry_async!(call, 3), Ok(3));
+    }
+}
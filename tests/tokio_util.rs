@@ -0,0 +1,18 @@
+#![cfg(feature = "tokio-util")]
+
+use postfix_macros::{postfix_macros, with_cancellation};
+use tokio_util::sync::CancellationToken;
+
+postfix_macros! {
+    #[tokio::test]
+    async fn tokio_util_with_cancellation() {
+        async fn job() -> u32 { 42 }
+        let token = CancellationToken::new();
+        assert_eq!(job().with_cancellation!(token), Some(42));
+
+        let pending = std::future::pending::<u32>();
+        let cancel = token.clone();
+        tokio::spawn(async move { cancel.cancel() });
+        assert_eq!(pending.with_cancellation!(token), None);
+    }
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "toml")]
+
+use postfix_macros::{postfix_macros, to_toml, to_toml_pretty, try_to_toml, try_to_toml_pretty};
+use std::collections::BTreeMap;
+
+postfix_macros! {
+    #[test]
+    fn toml_to_toml() {
+        let mut config = BTreeMap::new();
+        config.insert("name", "app");
+        config.insert("mode", "debug");
+        assert_eq!(config.to_toml!(), "mode = \"debug\"\nname = \"app\"\n");
+        assert_eq!(config.to_toml_pretty!(), "mode = \"debug\"\nname = \"app\"\n");
+        assert_eq!(config.try_to_toml!().unwrap(), "mode = \"debug\"\nname = \"app\"\n");
+        assert!(config.try_to_toml_pretty!().is_ok());
+        assert!("not a table".try_to_toml!().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "failed to serialize to TOML")]
+    fn toml_to_toml_panics() {
+        vec![1, 2].to_toml!();
+    }
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "tracing")]
+#![allow(unused_parens)]
+
+use postfix_macros::{debug_val, in_span, in_span_async, postfix_macros, trace_val};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use tracing::info_span;
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+fn poll_once<F: Future>(fut: F) -> Poll<F::Output> {
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = Context::from_waker(&waker);
+    let mut fut = Box::pin(fut);
+    Pin::new(&mut fut).poll(&mut cx)
+}
+
+postfix_macros! {
+    #[test]
+    fn tracing_pass_through() {
+        assert_eq!(42.trace_val!(answer), 42);
+        assert_eq!(Some(1).trace_val!(?maybe, "optional value"), Some(1));
+        assert_eq!("hi".debug_val!(%greeting), "hi");
+    }
+}
+
+postfix_macros! {
+    #[test]
+    fn tracing_in_span() {
+        let id = 21;
+        let v = (id * 2).in_span!(info_span!("compute", id = id));
+        assert_eq!(v, 42);
+
+        async fn answer() -> u32 {
+            42
+        }
+        let fut = answer().in_span_async!(info_span!("fut"));
+        assert_eq!(poll_once(fut), Poll::Ready(42));
+    }
+}
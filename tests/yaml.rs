@@ -0,0 +1,15 @@
+#![cfg(feature = "yaml")]
+
+use postfix_macros::{postfix_macros, to_yaml, try_to_yaml};
+use std::collections::BTreeMap;
+
+postfix_macros! {
+    #[test]
+    fn yaml_to_yaml() {
+        let mut map = BTreeMap::new();
+        map.insert("name", "app");
+        map.insert("mode", "debug");
+        assert_eq!(map.to_yaml!(), "mode: debug\nname: app\n");
+        assert_eq!(map.try_to_yaml!().unwrap(), "mode: debug\nname: app\n");
+    }
+}